@@ -0,0 +1,81 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use peuler::{PEuler, ProjectEuler};
+use pmath::digits::digit_rotations;
+use pmath::primes::sieve_of_eratosthenes;
+use pmath::sequences::CollatzSeq;
+use std::collections::HashSet;
+use std::hint::black_box;
+
+/// Benchmark a couple of fast problems, to quickly check that the benchmarks work.
+fn smoke(c: &mut Criterion) {
+    let project_euler = PEuler::with_ids(&[1, 2]);
+    let mut group = c.benchmark_group("smoke");
+    for problem in project_euler.problems() {
+        group.bench_function(BenchmarkId::from_parameter(problem.id()), |b| {
+            b.iter(|| black_box(problem.solve()))
+        });
+    }
+    group.finish();
+}
+
+/// Benchmark all available problems, grouped by their identifiers.
+fn problems(c: &mut Criterion) {
+    let project_euler = PEuler::new();
+    let mut group = c.benchmark_group("problems");
+    // some problems take seconds to solve, so the default of 100 samples would take too long
+    group.sample_size(10);
+    for problem in project_euler.problems() {
+        // `benchmark` clears the shared primes first, so that the sieve is measured too
+        group.bench_function(BenchmarkId::from_parameter(problem.id()), |b| {
+            b.iter_custom(|iters| (0..iters).map(|_| black_box(problem.benchmark()).1).sum())
+        });
+    }
+    group.finish();
+}
+
+/// Compare problem 14 with the Collatz sequence lengths memoized and followed independently.
+fn collatz(c: &mut Criterion) {
+    let project_euler = PEuler::with_ids(&[14]);
+    let problem = project_euler.problem(14).unwrap();
+    let mut group = c.benchmark_group("collatz");
+    group.sample_size(10);
+    group.bench_function("memoized", |b| b.iter(|| black_box(problem.solve())));
+    group.bench_function("independent", |b| {
+        b.iter(|| {
+            black_box(
+                (1u64..1_000_000)
+                    .max_by_key(|&n| CollatzSeq::new(n).count())
+                    .unwrap(),
+            )
+        })
+    });
+    group.finish();
+}
+
+/// Compare problem 35 with the rotations looked up in a table and in a hash set of the primes.
+fn circular_primes(c: &mut Criterion) {
+    let project_euler = PEuler::with_ids(&[35]);
+    let problem = project_euler.problem(35).unwrap();
+    let mut group = c.benchmark_group("circular_primes");
+    group.sample_size(10);
+    group.bench_function("table", |b| b.iter(|| black_box(problem.solve())));
+    // the problem takes the primes from the shared cache, so they are sieved only once here as well
+    let primes = sieve_of_eratosthenes(999_999u64);
+    group.bench_function("hash_set", |b| {
+        b.iter(|| {
+            let lookup = primes.iter().copied().collect::<HashSet<_>>();
+            black_box(
+                primes
+                    .iter()
+                    .filter(|&&prime| {
+                        digit_rotations(prime, 10).all(|rotation| lookup.contains(&rotation))
+                    })
+                    .count(),
+            )
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, smoke, problems, collatz, circular_primes);
+criterion_main!(benches);
@@ -1,10 +1,19 @@
 #![doc = include_str!("../README.md")]
 
+use malachite::Integer;
+use pmath::statistics::Sample;
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::error::Error as StdError;
 use std::fmt::{self, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::{Bound, RangeBounds};
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use std::sync::{Arc, PoisonError, RwLock, mpsc};
+use std::thread;
 use std::time::Duration;
 
+mod prime_cache;
 pub mod problems;
 
 /// An enum representing errors that can occur in this crate.
@@ -12,16 +21,106 @@ pub mod problems;
 pub enum Error {
     /// The requested problem is not available.
     UnavailableProblem,
+    /// The answer to the requested problem is not known.
+    UnavailableAnswer,
+    /// The solver did not finish within the given time.
+    Timeout,
+    /// A problem with the same identifier is already available.
+    DuplicateProblem,
+    /// The solver was cancelled before it finished.
+    Cancelled,
 }
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Self::UnavailableProblem => write!(f, "The requested problem is not available."),
+            Self::UnavailableAnswer => write!(f, "The answer to the problem is not known."),
+            Self::Timeout => write!(f, "The solver did not finish in time."),
+            Self::DuplicateProblem => write!(f, "The problem is already available."),
+            Self::Cancelled => write!(f, "The solver was cancelled."),
         }
     }
 }
 impl StdError for Error {}
 
+/// A solution to a problem.
+///
+/// [Display] gives the same output as [Solution::solve].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum SolutionValue {
+    /// An integer solution.
+    Integer(Integer),
+    /// A solution which is not an integer.
+    Text(String),
+}
+impl Display for SolutionValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(integer) => write!(f, "{integer}"),
+            Self::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+/// The result of solving a problem.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProblemResult {
+    /// The identifier of the problem.
+    pub id: usize,
+    /// The title of the problem.
+    pub title: String,
+    /// The solution to the problem.
+    pub answer: String,
+    /// The time it took to solve the problem, in nanoseconds.
+    pub duration_ns: u64,
+}
+
+/// The summary of repeated runs of a problem's solution.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BenchmarkResult {
+    /// The solution to the problem.
+    pub answer: String,
+    /// The number of measured runs.
+    pub iterations: usize,
+    /// The arithmetic mean of the elapsed times, in nanoseconds.
+    pub mean_ns: f64,
+    /// The sample standard deviation of the elapsed times, in nanoseconds.
+    /// It is `0` if there was only one run.
+    pub stddev_ns: f64,
+    /// The shortest elapsed time, in nanoseconds.
+    pub min_ns: u64,
+    /// The longest elapsed time, in nanoseconds.
+    pub max_ns: u64,
+    /// The median of the elapsed times, in nanoseconds.
+    pub median_ns: f64,
+}
+impl BenchmarkResult {
+    /// Summarize the elapsed times of repeated runs.
+    /// # Arguments
+    /// * `answer` - The solution to the problem.
+    /// * `sample` - The elapsed times in nanoseconds.
+    /// # Returns
+    /// * The summary of the runs.
+    /// # Panics
+    /// * If `sample` is empty.
+    pub fn new(answer: String, sample: &Sample<u64>) -> Self {
+        if sample.is_empty() {
+            panic!("Sample must not be empty.");
+        }
+        Self {
+            answer,
+            iterations: sample.len(),
+            mean_ns: sample.mean().unwrap(),
+            stddev_ns: sample.stddev().unwrap_or(0.0),
+            min_ns: *sample.iter().min().unwrap(),
+            max_ns: *sample.iter().max().unwrap(),
+            median_ns: sample.median().unwrap(),
+        }
+    }
+}
+
 /// A trait representing the [*Project Euler*](https://projecteuler.net).
 pub trait ProjectEuler: Send + Sync {
     /// Get all available problems.
@@ -29,6 +128,84 @@ pub trait ProjectEuler: Send + Sync {
     /// * An iterator over the problems, sorted by their identifier in ascending order.
     fn problems<'a>(&'a self) -> Box<dyn Iterator<Item = &'a dyn Problem> + 'a>;
 
+    /// Get the number of available problems.
+    /// # Returns
+    /// * The number of available problems.
+    fn count(&self) -> usize {
+        self.problems().count()
+    }
+
+    /// Get the problems whose identifiers are in the specified range.
+    /// # Arguments
+    /// * `range` - The range of identifiers, e.g. `30..=40`.
+    /// # Returns
+    /// * An iterator over the problems in the range, sorted by their identifier in ascending order.
+    fn problems_in_range<'a>(
+        &'a self,
+        range: impl RangeBounds<usize>,
+    ) -> Box<dyn Iterator<Item = &'a dyn Problem> + 'a>
+    where
+        Self: Sized,
+    {
+        let bounds = (range.start_bound().cloned(), range.end_bound().cloned());
+        Box::new(
+            self.problems()
+                .skip_while(move |problem| !bounds.contains(&problem.id()))
+                .take_while(move |problem| bounds.contains(&problem.id())),
+        )
+    }
+
+    /// Find the problems whose titles contain the specified text.
+    ///
+    /// The search is case-insensitive.
+    /// # Arguments
+    /// * `needle` - The text to search for.
+    /// # Returns
+    /// * The matching problems, sorted by their identifier in ascending order.
+    fn find_by_title<'a>(&'a self, needle: &str) -> Vec<&'a dyn Problem> {
+        let needle = needle.to_lowercase();
+        self.problems()
+            .filter(|problem| problem.title().to_lowercase().contains(&needle))
+            .collect()
+    }
+
+    /// Find the problems with the specified tag.
+    /// # Arguments
+    /// * `tag` - The tag to search for.
+    /// # Returns
+    /// * The problems having the tag, sorted by their identifier in ascending order.
+    fn problems_with_tag<'a>(&'a self, tag: &str) -> Vec<&'a dyn Problem> {
+        self.problems()
+            .filter(|problem| problem.tags().iter().any(|&problem_tag| problem_tag == tag))
+            .collect()
+    }
+
+    /// Get the identifiers of all available problems.
+    /// # Returns
+    /// * The identifiers of the problems in ascending order.
+    fn solved_ids(&self) -> Vec<usize> {
+        self.problems().map(|problem| problem.id()).collect()
+    }
+
+    /// Get the identifiers of problems that are not available.
+    /// # Arguments
+    /// * `up_to` - The largest identifier to consider.
+    /// # Returns
+    /// * The identifiers in `1..=up_to` without an available problem, in ascending order.
+    fn missing_ids(&self, up_to: usize) -> Vec<usize> {
+        let mut missing = Vec::new();
+        let mut next_id = 1;
+        for id in self.solved_ids() {
+            if id > up_to {
+                break;
+            }
+            missing.extend(next_id..id);
+            next_id = id + 1;
+        }
+        missing.extend(next_id..=up_to);
+        missing
+    }
+
     /// Get the problem with the specified identifier.
     /// # Arguments
     /// * `id` - The identifier of the problem to retrieve.
@@ -58,6 +235,28 @@ pub trait ProjectEuler: Send + Sync {
         Ok(self.problem(id)?.solve())
     }
 
+    /// Solve the specified problem, giving up when the flag is set.
+    /// # Arguments
+    /// * `id` - The identifier of the problem to solve.
+    /// * `cancel` - The flag requesting cancellation.
+    /// # Returns
+    /// * The solution to the problem or the [Error].
+    /// # Errors
+    /// * [Error::UnavailableProblem] - If the specified problem is not available.
+    /// * [Error::Cancelled] - If the solver is cancelled before it finishes.
+    fn solve_cancellable(&self, id: usize, cancel: &AtomicBool) -> Result<String, Error> {
+        self.problem(id)?.solve_cancellable(cancel)
+    }
+
+    /// Solve multiple problems.
+    /// # Arguments
+    /// * `ids` - The identifiers of the problems to solve.
+    /// # Returns
+    /// * The solution or the [Error] for each of the problems, in the same order as `ids`.
+    fn solve_many(&self, ids: &[usize]) -> Vec<Result<String, Error>> {
+        ids.iter().map(|&id| self.solve(id)).collect()
+    }
+
     /// Solve the specified problem and measure the elapsed time.
     /// # Arguments
     /// * `id` - The identifier of the problem to run.
@@ -68,6 +267,62 @@ pub trait ProjectEuler: Send + Sync {
     fn benchmark(&self, id: usize) -> Result<(String, Duration), Error> {
         Ok(self.problem(id)?.benchmark())
     }
+
+    /// Solve the specified problem and check the solution against the known answer.
+    /// # Arguments
+    /// * `id` - The identifier of the problem to verify.
+    /// # Returns
+    /// * Whether the solution matches the known answer or the [Error].
+    /// # Errors
+    /// * [Error::UnavailableProblem] - If the specified problem is not available.
+    /// * [Error::UnavailableAnswer] - If the answer to the specified problem is not known.
+    fn verify(&self, id: usize) -> Result<bool, Error> {
+        let problem = self.problem(id)?;
+        let answer = problem.answer().ok_or(Error::UnavailableAnswer)?;
+        Ok(problem.solve().trim() == answer)
+    }
+
+    /// Solve all available problems and measure the elapsed time.
+    /// # Returns
+    /// * The results for all problems, sorted by identifier in ascending order.
+    fn solve_all(&self) -> Vec<ProblemResult> {
+        self.problems()
+            .map(|problem| {
+                let (answer, elapsed) = problem.benchmark();
+                ProblemResult {
+                    id: problem.id(),
+                    title: problem.title().to_string(),
+                    answer,
+                    duration_ns: u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX),
+                }
+            })
+            .collect()
+    }
+
+    /// Solve all available problems and serialize the results as JSON.
+    /// # Returns
+    /// * The JSON array of [ProblemResult]s, sorted by identifier in ascending order.
+    #[cfg(feature = "serde")]
+    fn solve_all_json(&self) -> String {
+        serde_json::to_string(&self.solve_all()).expect("Cannot serialize the results.")
+    }
+
+    /// Solve all available problems in parallel.
+    ///
+    /// Problems are solved concurrently on the [rayon] thread pool.
+    /// # Returns
+    /// * The identifiers and solutions of all problems, sorted by identifier in ascending order.
+    #[cfg(feature = "rayon")]
+    fn solve_all_parallel(&self) -> Vec<(usize, String)> {
+        use rayon::prelude::*;
+
+        // collecting an indexed parallel iterator preserves the order of the problems
+        self.problems()
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .map(|problem| (problem.id(), problem.solve()))
+            .collect()
+    }
 }
 
 /// A trait representing the [*Project Euler*](https://projecteuler.net) problem.
@@ -81,6 +336,59 @@ pub trait Problem: Send + Sync + Solution {
     /// # Returns
     /// * The title of the problem.
     fn title(&self) -> &str;
+
+    /// The URL of the problem on the [*Project Euler*](https://projecteuler.net) website.
+    /// # Returns
+    /// * The URL of the problem's page.
+    fn url(&self) -> String {
+        format!("https://projecteuler.net/problem={}", self.id())
+    }
+
+    /// The known answer to the problem.
+    /// # Returns
+    /// * The answer to the problem, or [None] if it is not known.
+    fn answer(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// The difficulty rating of the problem.
+    /// # Returns
+    /// * The difficulty rating in percent as shown on the
+    ///   [*Project Euler*](https://projecteuler.net) website, or [None] if it is not known.
+    fn difficulty(&self) -> Option<u8> {
+        None
+    }
+
+    /// The category tags of the problem.
+    /// # Returns
+    /// * The tags describing the problem (e.g. `"primes"` or `"dynamic-programming"`).
+    fn tags(&self) -> &'static [&'static str] {
+        &[]
+    }
+}
+
+// problems are compared and hashed by their identifiers,
+// so they can be stored in sorted sets and maps
+impl PartialEq for dyn Problem + '_ {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+impl Eq for dyn Problem + '_ {}
+impl PartialOrd for dyn Problem + '_ {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for dyn Problem + '_ {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.id().cmp(&other.id())
+    }
+}
+impl Hash for dyn Problem + '_ {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id().hash(state);
+    }
 }
 
 /// A trait representing the [*Project Euler*](https://projecteuler.net) problem's solution.
@@ -90,13 +398,67 @@ pub trait Solution: Send + Sync {
     /// * The solution to the problem.
     fn solve(&self) -> String;
 
+    /// Solve the problem and write the solution into the buffer.
+    ///
+    /// The buffer is cleared first, so it can be reused between calls.
+    /// The default implementation copies the output of [Solution::solve].
+    /// # Arguments
+    /// * `buf` - The buffer for the solution.
+    fn solve_into(&self, buf: &mut String) {
+        buf.clear();
+        buf.push_str(&self.solve());
+    }
+
+    /// Solve the problem, giving up when the flag is set.
+    ///
+    /// The default implementation only checks the flag before calling [Solution::solve].
+    /// Long-running solvers should override it to check the flag periodically while solving.
+    /// # Arguments
+    /// * `cancel` - The flag requesting cancellation.
+    /// # Returns
+    /// * The solution to the problem or the [Error].
+    /// # Errors
+    /// * [Error::Cancelled] - If the flag is set before the solver finishes.
+    fn solve_cancellable(&self, cancel: &AtomicBool) -> Result<String, Error> {
+        if cancel.load(AtomicOrdering::Relaxed) {
+            Err(Error::Cancelled)
+        } else {
+            Ok(self.solve())
+        }
+    }
+
+    /// Solve the problem and return the typed solution.
+    ///
+    /// Solvers with an integer solution should override it to return
+    /// [SolutionValue::Integer] directly, and implement [Solution::solve] on top of it.
+    /// The default implementation is only a fallback for the other solvers:
+    /// it parses the output of [Solution::solve], and keeps it as [SolutionValue::Text]
+    /// unless it is an integer in the canonical form, so that the display is unchanged.
+    /// # Returns
+    /// * The solution to the problem.
+    fn solve_typed(&self) -> SolutionValue {
+        let solution = self.solve();
+        match solution.parse::<Integer>() {
+            // only accept the canonical form, so that the display matches
+            Ok(integer) if integer.to_string() == solution => SolutionValue::Integer(integer),
+            _ => SolutionValue::Text(solution),
+        }
+    }
+
     /// Solve the problem and measure the elapsed time.
+    ///
+    /// The primes shared between the solvers are cleared before the run,
+    /// so that the time includes the sieve even when the problem was solved before.
+    /// Calling [solve](Solution::solve) repeatedly, as a custom benchmark loop might,
+    /// reuses those primes and measures only a lookup for the problems that use them.
     /// # Returns
     /// * The solution to the problem and the elapsed time.
     fn benchmark(&self) -> (String, Duration) {
         let result;
         let elapsed;
 
+        prime_cache::clear();
+
         #[cfg(not(all(
             target_arch = "wasm32",
             target_vendor = "unknown",
@@ -128,114 +490,297 @@ pub trait Solution: Send + Sync {
 
         (result, elapsed)
     }
+
+    /// Solve the problem multiple times and measure the elapsed time of each run.
+    /// # Arguments
+    /// * `iterations` - The number of times to solve the problem.
+    /// # Returns
+    /// * The solution to the problem and the elapsed times in nanoseconds.
+    /// # Panics
+    /// * If `iterations` is `0`.
+    fn benchmark_n(&self, iterations: usize) -> (String, Sample<u64>) {
+        self.benchmark_warmup(0, iterations)
+    }
+
+    /// Solve the problem multiple times and summarize the elapsed times.
+    /// # Arguments
+    /// * `iterations` - The number of times to solve the problem.
+    /// # Returns
+    /// * The solution to the problem and the statistics of the elapsed times.
+    /// # Panics
+    /// * If `iterations` is `0`.
+    fn benchmark_stats(&self, iterations: usize) -> BenchmarkResult {
+        let (answer, sample) = self.benchmark_n(iterations);
+        BenchmarkResult::new(answer, &sample)
+    }
+
+    /// Solve the problem multiple times after warming up and measure the elapsed time of each run.
+    ///
+    /// The warmup runs are not measured, so that cold caches do not skew the results.
+    /// # Arguments
+    /// * `warmup` - The number of times to solve the problem before measuring.
+    /// * `iterations` - The number of times to solve the problem while measuring.
+    /// # Returns
+    /// * The solution to the problem and the elapsed times in nanoseconds.
+    /// # Panics
+    /// * If `iterations` is `0`.
+    fn benchmark_warmup(&self, warmup: usize, iterations: usize) -> (String, Sample<u64>) {
+        if iterations == 0 {
+            panic!("Number of iterations must be greater than 0.");
+        }
+        for _ in 0..warmup {
+            self.solve();
+        }
+        let mut result = String::new();
+        let sample = (0..iterations)
+            .map(|_| {
+                let (solution, elapsed) = self.benchmark();
+                result = solution;
+                u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX)
+            })
+            .collect();
+        (result, sample)
+    }
+}
+
+/// A trait representing the [*Project Euler*](https://projecteuler.net) problem's solution
+/// which can be calculated for custom inputs.
+///
+/// [Solution::solve] is expected to call [ParametrizedSolution::solve_with]
+/// with the parameters given in the problem.
+pub trait ParametrizedSolution: Solution {
+    /// The parameters of the problem.
+    type Params;
+
+    /// Solve the problem with the specified parameters.
+    /// # Arguments
+    /// * `params` - The parameters of the problem.
+    /// # Returns
+    /// * The solution to the problem.
+    fn solve_with(&self, params: Self::Params) -> String;
 }
 
+/// A function creating a problem.
+type ProblemConstructor = fn() -> Arc<dyn Problem>;
+
+/// Identifiers and constructors of all available problems.
+const PROBLEMS: &[(usize, ProblemConstructor)] = &[
+    (1, || Arc::new(problems::Problem0001::new())),
+    (2, || Arc::new(problems::Problem0002::new())),
+    (3, || Arc::new(problems::Problem0003::new())),
+    (4, || Arc::new(problems::Problem0004::new())),
+    (5, || Arc::new(problems::Problem0005::new())),
+    (6, || Arc::new(problems::Problem0006::new())),
+    (7, || Arc::new(problems::Problem0007::new())),
+    (8, || Arc::new(problems::Problem0008::new())),
+    (9, || Arc::new(problems::Problem0009::new())),
+    (10, || Arc::new(problems::Problem0010::new())),
+    (11, || Arc::new(problems::Problem0011::new())),
+    (12, || Arc::new(problems::Problem0012::new())),
+    (13, || Arc::new(problems::Problem0013::new())),
+    (14, || Arc::new(problems::Problem0014::new())),
+    (15, || Arc::new(problems::Problem0015::new())),
+    (16, || Arc::new(problems::Problem0016::new())),
+    (17, || Arc::new(problems::Problem0017::new())),
+    (18, || Arc::new(problems::Problem0018::new())),
+    (19, || Arc::new(problems::Problem0019::new())),
+    (20, || Arc::new(problems::Problem0020::new())),
+    (21, || Arc::new(problems::Problem0021::new())),
+    (22, || Arc::new(problems::Problem0022::new())),
+    (23, || Arc::new(problems::Problem0023::new())),
+    (24, || Arc::new(problems::Problem0024::new())),
+    (25, || Arc::new(problems::Problem0025::new())),
+    (26, || Arc::new(problems::Problem0026::new())),
+    (27, || Arc::new(problems::Problem0027::new())),
+    (28, || Arc::new(problems::Problem0028::new())),
+    (29, || Arc::new(problems::Problem0029::new())),
+    (30, || Arc::new(problems::Problem0030::new())),
+    (31, || Arc::new(problems::Problem0031::new())),
+    (32, || Arc::new(problems::Problem0032::new())),
+    (33, || Arc::new(problems::Problem0033::new())),
+    (34, || Arc::new(problems::Problem0034::new())),
+    (35, || Arc::new(problems::Problem0035::new())),
+    (36, || Arc::new(problems::Problem0036::new())),
+    (37, || Arc::new(problems::Problem0037::new())),
+    (38, || Arc::new(problems::Problem0038::new())),
+    (39, || Arc::new(problems::Problem0039::new())),
+    (40, || Arc::new(problems::Problem0040::new())),
+    (41, || Arc::new(problems::Problem0041::new())),
+    (42, || Arc::new(problems::Problem0042::new())),
+    (43, || Arc::new(problems::Problem0043::new())),
+    (44, || Arc::new(problems::Problem0044::new())),
+    (45, || Arc::new(problems::Problem0045::new())),
+    (46, || Arc::new(problems::Problem0046::new())),
+    (47, || Arc::new(problems::Problem0047::new())),
+    (48, || Arc::new(problems::Problem0048::new())),
+    (49, || Arc::new(problems::Problem0049::new())),
+    (50, || Arc::new(problems::Problem0050::new())),
+    (52, || Arc::new(problems::Problem0052::new())),
+    (53, || Arc::new(problems::Problem0053::new())),
+    (54, || Arc::new(problems::Problem0054::new())),
+    (55, || Arc::new(problems::Problem0055::new())),
+    (56, || Arc::new(problems::Problem0056::new())),
+    (57, || Arc::new(problems::Problem0057::new())),
+    (58, || Arc::new(problems::Problem0058::new())),
+    (59, || Arc::new(problems::Problem0059::new())),
+    (61, || Arc::new(problems::Problem0061::new())),
+    (62, || Arc::new(problems::Problem0062::new())),
+    (63, || Arc::new(problems::Problem0063::new())),
+    (64, || Arc::new(problems::Problem0064::new())),
+    (65, || Arc::new(problems::Problem0065::new())),
+    (66, || Arc::new(problems::Problem0066::new())),
+    (67, || Arc::new(problems::Problem0067::new())),
+    (69, || Arc::new(problems::Problem0069::new())),
+    (70, || Arc::new(problems::Problem0070::new())),
+    (71, || Arc::new(problems::Problem0071::new())),
+    (72, || Arc::new(problems::Problem0072::new())),
+    (73, || Arc::new(problems::Problem0073::new())),
+    (74, || Arc::new(problems::Problem0074::new())),
+    (75, || Arc::new(problems::Problem0075::new())),
+    (76, || Arc::new(problems::Problem0076::new())),
+    (77, || Arc::new(problems::Problem0077::new())),
+    (78, || Arc::new(problems::Problem0078::new())),
+    (80, || Arc::new(problems::Problem0080::new())),
+    (81, || Arc::new(problems::Problem0081::new())),
+    (82, || Arc::new(problems::Problem0082::new())),
+    (85, || Arc::new(problems::Problem0085::new())),
+    (89, || Arc::new(problems::Problem0089::new())),
+    (91, || Arc::new(problems::Problem0091::new())),
+    (92, || Arc::new(problems::Problem0092::new())),
+    (95, || Arc::new(problems::Problem0095::new())),
+    (97, || Arc::new(problems::Problem0097::new())),
+    (98, || Arc::new(problems::Problem0098::new())),
+    (99, || Arc::new(problems::Problem0099::new())),
+    (100, || Arc::new(problems::Problem0100::new())),
+    (102, || Arc::new(problems::Problem0102::new())),
+];
+
 /// A structure representing the [*Project Euler*](https://projecteuler.net).
 pub struct PEuler {
-    problems: Vec<Box<dyn Problem>>,
+    problems: Vec<Arc<dyn Problem>>,
+    cache: RwLock<HashMap<usize, String>>,
 }
 impl PEuler {
     /// Create a new [PEuler] instance.
     /// # Returns
     /// * The new [PEuler] instance with all available problems initialized.
     pub fn new() -> Self {
+        Self::from_constructors(PROBLEMS.iter())
+    }
+
+    /// Create a new [PEuler] instance with only the specified problems.
+    ///
+    /// Unavailable problems are skipped.
+    /// # Arguments
+    /// * `ids` - The identifiers of the problems to initialize.
+    /// # Returns
+    /// * The new [PEuler] instance with the specified problems initialized.
+    pub fn with_ids(ids: &[usize]) -> Self {
+        Self::from_constructors(PROBLEMS.iter().filter(|(id, _)| ids.contains(id)))
+    }
+
+    /// Create a new [PEuler] instance from the problem constructors.
+    fn from_constructors<'a, I>(constructors: I) -> Self
+    where
+        I: Iterator<Item = &'a (usize, ProblemConstructor)>,
+    {
         let mut new_obj = Self {
-            problems: vec![
-                Box::new(problems::Problem0001::new()),
-                Box::new(problems::Problem0002::new()),
-                Box::new(problems::Problem0003::new()),
-                Box::new(problems::Problem0004::new()),
-                Box::new(problems::Problem0005::new()),
-                Box::new(problems::Problem0006::new()),
-                Box::new(problems::Problem0007::new()),
-                Box::new(problems::Problem0008::new()),
-                Box::new(problems::Problem0009::new()),
-                Box::new(problems::Problem0010::new()),
-                Box::new(problems::Problem0011::new()),
-                Box::new(problems::Problem0012::new()),
-                Box::new(problems::Problem0013::new()),
-                Box::new(problems::Problem0014::new()),
-                Box::new(problems::Problem0015::new()),
-                Box::new(problems::Problem0016::new()),
-                Box::new(problems::Problem0017::new()),
-                Box::new(problems::Problem0018::new()),
-                Box::new(problems::Problem0019::new()),
-                Box::new(problems::Problem0020::new()),
-                Box::new(problems::Problem0021::new()),
-                Box::new(problems::Problem0022::new()),
-                Box::new(problems::Problem0023::new()),
-                Box::new(problems::Problem0024::new()),
-                Box::new(problems::Problem0025::new()),
-                Box::new(problems::Problem0026::new()),
-                Box::new(problems::Problem0027::new()),
-                Box::new(problems::Problem0028::new()),
-                Box::new(problems::Problem0029::new()),
-                Box::new(problems::Problem0030::new()),
-                Box::new(problems::Problem0031::new()),
-                Box::new(problems::Problem0032::new()),
-                Box::new(problems::Problem0033::new()),
-                Box::new(problems::Problem0034::new()),
-                Box::new(problems::Problem0035::new()),
-                Box::new(problems::Problem0036::new()),
-                Box::new(problems::Problem0037::new()),
-                Box::new(problems::Problem0038::new()),
-                Box::new(problems::Problem0039::new()),
-                Box::new(problems::Problem0040::new()),
-                Box::new(problems::Problem0041::new()),
-                Box::new(problems::Problem0042::new()),
-                Box::new(problems::Problem0043::new()),
-                Box::new(problems::Problem0044::new()),
-                Box::new(problems::Problem0045::new()),
-                Box::new(problems::Problem0046::new()),
-                Box::new(problems::Problem0047::new()),
-                Box::new(problems::Problem0048::new()),
-                Box::new(problems::Problem0049::new()),
-                Box::new(problems::Problem0050::new()),
-                Box::new(problems::Problem0052::new()),
-                Box::new(problems::Problem0053::new()),
-                Box::new(problems::Problem0054::new()),
-                Box::new(problems::Problem0055::new()),
-                Box::new(problems::Problem0056::new()),
-                Box::new(problems::Problem0057::new()),
-                Box::new(problems::Problem0058::new()),
-                Box::new(problems::Problem0059::new()),
-                Box::new(problems::Problem0061::new()),
-                Box::new(problems::Problem0062::new()),
-                Box::new(problems::Problem0063::new()),
-                Box::new(problems::Problem0064::new()),
-                Box::new(problems::Problem0065::new()),
-                Box::new(problems::Problem0066::new()),
-                Box::new(problems::Problem0067::new()),
-                Box::new(problems::Problem0069::new()),
-                Box::new(problems::Problem0070::new()),
-                Box::new(problems::Problem0071::new()),
-                Box::new(problems::Problem0072::new()),
-                Box::new(problems::Problem0073::new()),
-                Box::new(problems::Problem0074::new()),
-                Box::new(problems::Problem0075::new()),
-                Box::new(problems::Problem0076::new()),
-                Box::new(problems::Problem0077::new()),
-                Box::new(problems::Problem0078::new()),
-                Box::new(problems::Problem0080::new()),
-                Box::new(problems::Problem0081::new()),
-                Box::new(problems::Problem0082::new()),
-                Box::new(problems::Problem0085::new()),
-                Box::new(problems::Problem0089::new()),
-                Box::new(problems::Problem0091::new()),
-                Box::new(problems::Problem0092::new()),
-                Box::new(problems::Problem0095::new()),
-                Box::new(problems::Problem0097::new()),
-                Box::new(problems::Problem0098::new()),
-                Box::new(problems::Problem0099::new()),
-                Box::new(problems::Problem0100::new()),
-                Box::new(problems::Problem0102::new()),
-            ],
+            problems: constructors.map(|(_, constructor)| constructor()).collect(),
+            cache: RwLock::default(),
         };
         new_obj
             .problems
             .sort_unstable_by_key(|problem| problem.id());
         new_obj
     }
+
+    /// Add a problem.
+    /// # Arguments
+    /// * `problem` - The problem to add.
+    /// # Errors
+    /// * [Error::DuplicateProblem] - If a problem with the same identifier is already available.
+    pub fn register(&mut self, problem: Box<dyn Problem>) -> Result<(), Error> {
+        match self
+            .problems
+            .binary_search_by_key(&problem.id(), |iter_problem| iter_problem.id())
+        {
+            Ok(_) => Err(Error::DuplicateProblem),
+            Err(index) => {
+                self.problems.insert(index, Arc::from(problem));
+                Ok(())
+            }
+        }
+    }
+
+    /// Solve the specified problem, reusing the solution if it was already computed.
+    ///
+    /// Solutions are deterministic, so each problem is solved at most once
+    /// (unless multiple threads request it at the same time) and the solution
+    /// is stored for subsequent calls.
+    /// # Arguments
+    /// * `id` - The identifier of the problem to solve.
+    /// # Returns
+    /// * The solution to the problem or the [Error].
+    /// # Errors
+    /// * [Error::UnavailableProblem] - If the specified problem is not available.
+    pub fn solve_cached(&self, id: usize) -> Result<String, Error> {
+        if let Some(solution) = self
+            .cache
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&id)
+        {
+            return Ok(solution.clone());
+        }
+        let solution = self.solve(id)?;
+        self.cache
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(id, solution.clone());
+        Ok(solution)
+    }
+
+    /// Solve the specified problem, giving up if it takes too long.
+    ///
+    /// The solver runs on a separate thread. If it does not finish in time,
+    /// the thread is left running in the background and its result is discarded.
+    /// # Arguments
+    /// * `id` - The identifier of the problem to solve.
+    /// * `timeout` - The maximum time to wait for the solution.
+    /// # Returns
+    /// * The solution to the problem or the [Error].
+    /// # Errors
+    /// * [Error::UnavailableProblem] - If the specified problem is not available.
+    /// * [Error::Timeout] - If the solver does not finish within `timeout`.
+    /// # Panics
+    /// * If the solver panics.
+    /// * If threads are not supported on the target platform.
+    pub fn solve_with_timeout(&self, id: usize, timeout: Duration) -> Result<String, Error> {
+        let problem = Arc::clone(&self.problems[self.problem_index(id)?]);
+
+        let (sender, receiver) = mpsc::channel();
+        let handle = thread::spawn(move || {
+            // the receiver is gone if we timed out, so the result is simply dropped
+            let _ = sender.send(problem.solve());
+        });
+        match receiver.recv_timeout(timeout) {
+            Ok(solution) => Ok(solution),
+            Err(mpsc::RecvTimeoutError::Timeout) => Err(Error::Timeout),
+            Err(mpsc::RecvTimeoutError::Disconnected) => match handle.join() {
+                Err(panic) => std::panic::resume_unwind(panic),
+                Ok(()) => unreachable!("The solver finished without sending the solution."),
+            },
+        }
+    }
+
+    /// Find the index of the specified problem in the sorted problems.
+    /// # Errors
+    /// * [Error::UnavailableProblem] - If the specified problem is not available.
+    fn problem_index(&self, id: usize) -> Result<usize, Error> {
+        self.problems
+            .binary_search_by_key(&id, |problem| problem.id())
+            .map_err(|_| Error::UnavailableProblem)
+    }
 }
 impl Default for PEuler {
     fn default() -> Self {
@@ -246,4 +791,228 @@ impl ProjectEuler for PEuler {
     fn problems<'a>(&'a self) -> Box<dyn Iterator<Item = &'a dyn Problem> + 'a> {
         Box::new(self.problems.iter().map(|problem| problem.as_ref()))
     }
+
+    fn count(&self) -> usize {
+        self.problems.len()
+    }
+
+    fn problem(&self, id: usize) -> Result<&dyn Problem, Error> {
+        Ok(self.problems[self.problem_index(id)?].as_ref())
+    }
+
+    fn problems_in_range<'a>(
+        &'a self,
+        range: impl RangeBounds<usize>,
+    ) -> Box<dyn Iterator<Item = &'a dyn Problem> + 'a> {
+        // problems are sorted by id, so the range is a contiguous slice
+        let start = self
+            .problems
+            .partition_point(|problem| match range.start_bound() {
+                Bound::Included(&start) => problem.id() < start,
+                Bound::Excluded(&start) => problem.id() <= start,
+                Bound::Unbounded => false,
+            });
+        let end = self
+            .problems
+            .partition_point(|problem| match range.end_bound() {
+                Bound::Included(&end) => problem.id() <= end,
+                Bound::Excluded(&end) => problem.id() < end,
+                Bound::Unbounded => true,
+            })
+            .max(start);
+        Box::new(
+            self.problems[start..end]
+                .iter()
+                .map(|problem| problem.as_ref()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    struct SleepyProblem {
+        id: usize,
+        delay: Duration,
+    }
+    impl Problem for SleepyProblem {
+        fn id(&self) -> usize {
+            self.id
+        }
+
+        fn title(&self) -> &str {
+            "Sleepy Problem"
+        }
+    }
+    impl Solution for SleepyProblem {
+        fn solve(&self) -> String {
+            thread::sleep(self.delay);
+            self.id.to_string()
+        }
+    }
+
+    fn sleepy_peuler() -> PEuler {
+        PEuler {
+            problems: vec![
+                Arc::new(SleepyProblem {
+                    id: 1,
+                    delay: Duration::ZERO,
+                }),
+                Arc::new(SleepyProblem {
+                    id: 2,
+                    delay: Duration::from_secs(10),
+                }),
+            ],
+            cache: RwLock::default(),
+        }
+    }
+
+    struct CountingProblem {
+        solves: Arc<AtomicUsize>,
+    }
+    impl Problem for CountingProblem {
+        fn id(&self) -> usize {
+            1
+        }
+
+        fn title(&self) -> &str {
+            "Counting Problem"
+        }
+    }
+    impl Solution for CountingProblem {
+        fn solve(&self) -> String {
+            self.solves.fetch_add(1, AtomicOrdering::Relaxed);
+            String::from("42")
+        }
+    }
+
+    struct CancellableProblem;
+    impl Problem for CancellableProblem {
+        fn id(&self) -> usize {
+            1
+        }
+
+        fn title(&self) -> &str {
+            "Cancellable Problem"
+        }
+    }
+    impl Solution for CancellableProblem {
+        fn solve(&self) -> String {
+            self.solve_cancellable(&AtomicBool::new(false)).unwrap()
+        }
+
+        fn solve_cancellable(&self, cancel: &AtomicBool) -> Result<String, Error> {
+            // never finishes on its own
+            while !cancel.load(AtomicOrdering::Relaxed) {
+                thread::sleep(Duration::from_millis(1));
+            }
+            Err(Error::Cancelled)
+        }
+    }
+
+    #[test]
+    fn solve_cancellable() {
+        //! Test that setting the flag makes a cooperating solver bail out
+
+        let project_euler = PEuler {
+            problems: vec![Arc::new(CancellableProblem)],
+            cache: RwLock::default(),
+        };
+        let cancel = AtomicBool::new(false);
+
+        let result = thread::scope(|scope| {
+            let solver = scope.spawn(|| project_euler.solve_cancellable(1, &cancel));
+            thread::sleep(Duration::from_millis(50));
+            cancel.store(true, AtomicOrdering::Relaxed);
+            solver.join().unwrap()
+        });
+        assert_eq!(result, Err(Error::Cancelled));
+
+        assert_eq!(
+            project_euler.solve_cancellable(2, &cancel),
+            Err(Error::UnavailableProblem)
+        );
+    }
+
+    #[test]
+    fn solve_cancellable_default() {
+        //! Test that the default [Solution::solve_cancellable] checks the flag before solving
+
+        let solves = Arc::new(AtomicUsize::new(0));
+        let problem = CountingProblem {
+            solves: Arc::clone(&solves),
+        };
+
+        assert_eq!(
+            problem.solve_cancellable(&AtomicBool::new(false)),
+            Ok(String::from("42"))
+        );
+        assert_eq!(
+            problem.solve_cancellable(&AtomicBool::new(true)),
+            Err(Error::Cancelled)
+        );
+        assert_eq!(solves.load(AtomicOrdering::Relaxed), 1);
+    }
+
+    #[test]
+    fn solve_cached() {
+        //! Test that [PEuler::solve_cached] solves the problem only once
+
+        let solves = Arc::new(AtomicUsize::new(0));
+        let project_euler = PEuler {
+            problems: vec![Arc::new(CountingProblem {
+                solves: Arc::clone(&solves),
+            })],
+            cache: RwLock::default(),
+        };
+
+        assert_eq!(project_euler.solve_cached(1), Ok(String::from("42")));
+        assert_eq!(solves.load(AtomicOrdering::Relaxed), 1);
+        assert_eq!(project_euler.solve_cached(1), Ok(String::from("42")));
+        assert_eq!(solves.load(AtomicOrdering::Relaxed), 1);
+
+        // uncached solving is not affected
+        assert_eq!(project_euler.solve(1), Ok(String::from("42")));
+        assert_eq!(solves.load(AtomicOrdering::Relaxed), 2);
+
+        assert_eq!(
+            project_euler.solve_cached(2),
+            Err(Error::UnavailableProblem)
+        );
+    }
+
+    #[test]
+    fn solve_with_timeout_fast() {
+        //! Test that [PEuler::solve_with_timeout] returns the solution of a fast solver
+
+        let project_euler = sleepy_peuler();
+        assert_eq!(
+            project_euler.solve_with_timeout(1, Duration::from_secs(10)),
+            Ok(String::from("1"))
+        );
+    }
+
+    #[test]
+    fn solve_with_timeout_slow() {
+        //! Test that [PEuler::solve_with_timeout] times out on a slow solver
+
+        let project_euler = sleepy_peuler();
+        assert_eq!(
+            project_euler.solve_with_timeout(2, Duration::from_millis(50)),
+            Err(Error::Timeout)
+        );
+    }
+
+    #[test]
+    fn solve_with_timeout_unavailable() {
+        //! Test that [PEuler::solve_with_timeout] returns an error for an unavailable problem
+
+        let project_euler = sleepy_peuler();
+        assert_eq!(
+            project_euler.solve_with_timeout(3, Duration::from_secs(1)),
+            Err(Error::UnavailableProblem)
+        );
+    }
 }
@@ -1,19 +1,94 @@
-use clap::{Arg, ArgAction, command, value_parser};
-use std::io::{Write, stdout};
+use clap::{Arg, ArgAction, ArgMatches, Command, command, value_parser};
+use clap_complete::{Shell, generate};
+#[cfg(feature = "serde")]
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt::{self, Display, Formatter};
+use std::fs::File;
+#[cfg(any(feature = "color", feature = "progress"))]
+use std::io::IsTerminal;
+#[cfg(feature = "progress")]
+use std::io::stderr;
+use std::io::{self, BufRead, BufReader, BufWriter, Write, stdin, stdout};
+#[cfg(feature = "serde")]
+use std::path::Path;
+use std::path::PathBuf;
 use std::process::ExitCode;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
 
-use peuler::{PEuler, ProjectEuler};
-use pmath::statistics::Sample;
+#[cfg(feature = "progress")]
+use indicatif::ProgressBar;
+use peuler::{BenchmarkResult, PEuler, Problem, ProjectEuler};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Benchmark means from this duration upwards are considered slow.
+const SLOW_NS: f64 = 1e9;
 
 fn main() -> ExitCode {
-    let argv = command!()
+    let argv = cli().get_matches();
+
+    if let Some(("completions", completions_argv)) = argv.subcommand() {
+        let shell = *completions_argv.get_one::<Shell>("shell").unwrap();
+        let mut command = cli();
+        let name = command.get_name().to_string();
+        generate(shell, &mut command, name, &mut stdout());
+        return ExitCode::SUCCESS;
+    }
+    #[cfg(feature = "serde")]
+    if let Some(("compare", compare_argv)) = argv.subcommand() {
+        let palette = Palette {
+            enabled: color_enabled(compare_argv),
+        };
+        return match compare(compare_argv, palette, &mut stdout()) {
+            Ok(exit_code) => exit_code,
+            Err(e) => {
+                eprintln!("Error: {e}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let mut out: Box<dyn Write> = match argv.get_one::<PathBuf>("output") {
+        Some(path) => match File::create(path) {
+            Ok(file) => Box::new(BufWriter::new(file)),
+            Err(e) => {
+                eprintln!("Error: {}: {e}", path.display());
+                return ExitCode::FAILURE;
+            }
+        },
+        None => Box::new(stdout()),
+    };
+
+    match run(&argv, &mut out) {
+        Ok(exit_code) => exit_code,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Build the command line interface.
+fn cli() -> Command {
+    let command = command!()
         .arg(
             Arg::new("problem")
                 .value_name("PROBLEM")
-                .help("The problem identifier")
-                .required_unless_present_any(["list", "count", "solutions", "benchmark"])
+                .help("The problem identifier(s), e.g. 7, 1-10 or 1-5,9 (- reads them from stdin)")
+                .required_unless_present_any([
+                    "list",
+                    "count",
+                    "solutions",
+                    "benchmark",
+                    "verify",
+                    "repl",
+                    "from-file",
+                ])
                 .conflicts_with_all(["list", "count", "solutions"])
-                .value_parser(value_parser!(u16).range(1..)),
+                .value_parser(parse_problem_spec),
         )
         .arg(
             Arg::new("list")
@@ -53,133 +128,1298 @@ fn main() -> ExitCode {
                 .value_parser(value_parser!(u64).range(3..))
                 .conflicts_with_all(["list", "count", "solutions"]),
         )
-        .get_matches();
+        .arg(
+            Arg::new("warmup")
+                .short('w')
+                .long("warmup")
+                .value_name("WARMUP")
+                .help("Number of unmeasured runs before benchmarking")
+                .required(false)
+                .requires("benchmark")
+                .value_parser(value_parser!(u64)),
+        )
+        .arg(
+            Arg::new("total")
+                .short('t')
+                .long("total")
+                .action(ArgAction::SetTrue)
+                .help("Print the total mean time and the slowest problem after benchmarking")
+                .required(false)
+                .requires("benchmark"),
+        )
+        .arg(
+            Arg::new("slower-than")
+                .long("slower-than")
+                .value_name("DURATION")
+                .help("Print only the benchmarks with a mean above the duration, e.g. 1ms or 500us")
+                .required(false)
+                .requires("benchmark")
+                .value_parser(parse_duration),
+        )
+        .arg(
+            Arg::new("verify")
+                .long("verify")
+                .action(ArgAction::SetTrue)
+                .help("Verify the solutions against the known answers")
+                .required(false)
+                .conflicts_with_all(["list", "count", "solutions", "benchmark"]),
+        )
+        .arg(
+            Arg::new("repl")
+                .long("repl")
+                .action(ArgAction::SetTrue)
+                .help("Start an interactive loop which solves the entered problems")
+                .required(false)
+                .conflicts_with_all([
+                    "problem",
+                    "list",
+                    "count",
+                    "solutions",
+                    "benchmark",
+                    "verify",
+                ]),
+        )
+        .arg(
+            Arg::new("from-file")
+                .short('f')
+                .long("from-file")
+                .value_name("PATH")
+                .help("Read the problem identifiers from a file, one per line")
+                .required(false)
+                .conflicts_with_all(["problem", "list", "count", "solutions", "repl"])
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            Arg::new("output")
+                .short('o')
+                .long("output")
+                .value_name("PATH")
+                .help("Write the output to a file instead of stdout")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            Arg::new("quiet")
+                .short('q')
+                .long("quiet")
+                .action(ArgAction::SetTrue)
+                .help("Print only the results, without the problem prefixes")
+                .required(false)
+                .conflicts_with_all(["list", "count"]),
+        );
+    #[cfg(feature = "color")]
+    let command = command.arg(color_arg());
+    #[cfg(feature = "rayon")]
+    let command = command.arg(
+        Arg::new("parallel")
+            .short('p')
+            .long("parallel")
+            .action(ArgAction::SetTrue)
+            .help("Compute the results for multiple problems in parallel")
+            .required(false)
+            .conflicts_with_all(["list", "count"]),
+    );
+    #[cfg(feature = "serde")]
+    let command = command
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .value_name("PATH")
+                .help("Write the benchmark results to a JSON file, e.g. for the compare subcommand")
+                .required(false)
+                .requires("benchmark")
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .subcommand({
+            let compare = Command::new("compare")
+                .about("Compare the mean times of two JSON benchmark exports written with --json")
+                .arg(
+                    Arg::new("baseline")
+                        .value_name("BASELINE")
+                        .help("The export to compare against")
+                        .required(true)
+                        .value_parser(value_parser!(PathBuf)),
+                )
+                .arg(
+                    Arg::new("current")
+                        .value_name("CURRENT")
+                        .help("The export to compare")
+                        .required(true)
+                        .value_parser(value_parser!(PathBuf)),
+                );
+            // the top-level arguments conflict with the subcommands, so it needs its own
+            #[cfg(feature = "color")]
+            let compare = compare.arg(color_arg());
+            compare
+        });
 
+    command
+        .subcommand(
+            Command::new("completions")
+                .about("Print a shell completion script")
+                .arg(
+                    Arg::new("shell")
+                        .value_name("SHELL")
+                        .help("The shell to generate the completion script for")
+                        .required(true)
+                        .value_parser(value_parser!(Shell)),
+                ),
+        )
+        .subcommand_negates_reqs(true)
+        .args_conflicts_with_subcommands(true)
+}
+
+/// The argument which selects when to colorize the output.
+#[cfg(feature = "color")]
+fn color_arg() -> Arg {
+    Arg::new("color")
+        .long("color")
+        .value_name("WHEN")
+        .help("When to colorize the output")
+        .required(false)
+        .default_value("auto")
+        .value_parser(["auto", "always", "never"])
+}
+
+/// Run the command given by the arguments, writing the output to `out`.
+fn run(argv: &ArgMatches, out: &mut dyn Write) -> io::Result<ExitCode> {
     let list_flag: bool = argv.get_flag("list");
     let count_flag: bool = argv.get_flag("count");
     let solutions_flag: bool = argv.get_flag("solutions");
-    let problem_id = argv.get_one::<u16>("problem").map(|&u| u as usize);
+    let verify_flag: bool = argv.get_flag("verify");
+    let repl_flag: bool = argv.get_flag("repl");
+    let total_flag: bool = argv.get_flag("total");
+    let slower_than_ns = argv.get_one::<f64>("slower-than").copied();
+    let problem_ids = match selected_ids(argv) {
+        Ok(ids) => ids,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return Ok(ExitCode::FAILURE);
+        }
+    };
     let benchmark_iterations = argv.get_one::<u64>("benchmark").copied();
+    let warmup_iterations = argv.get_one::<u64>("warmup").copied().unwrap_or(0);
+    let palette = Palette {
+        enabled: color_enabled(argv),
+    };
+    let options = TableOptions {
+        palette,
+        #[cfg(feature = "rayon")]
+        parallel: argv.get_flag("parallel"),
+        #[cfg(not(feature = "rayon"))]
+        parallel: false,
+        quiet: argv.get_flag("quiet"),
+    };
 
     let project_euler = PEuler::new();
 
     if list_flag {
-        for problem in project_euler.problems() {
-            println!("Problem {:04}: {}", problem.id(), problem.title());
-        }
+        print_list(out, &project_euler, palette)?;
+    } else if repl_flag {
+        repl(&project_euler, palette, stdin().lock(), out)?;
     } else if count_flag {
-        println!("{}", project_euler.problems().count());
+        writeln!(out, "{}", project_euler.count())?;
     } else if solutions_flag {
-        let max_line_len = project_euler
-            .problems()
-            .map(|problem| problem.title().chars().count() + 14)
-            .max()
-            .unwrap_or(0);
-        for problem in project_euler.problems() {
-            print!(
-                "Problem {:04}: {:width$} => ",
-                problem.id(),
-                problem.title(),
-                width = max_line_len - 14
-            );
-            let _ = stdout().flush();
-            println!("{}", problem.solve());
+        let problems = project_euler.problems().collect::<Vec<_>>();
+        print_solutions(out, options, &problems)?;
+    } else if verify_flag {
+        let problems = match problem_ids.as_deref() {
+            Some(ids) => select_problems(&project_euler, ids),
+            None => project_euler.problems().collect(),
+        };
+        if print_verification(out, options, &problems)? > 0 {
+            out.flush()?;
+            return Ok(ExitCode::FAILURE);
         }
     } else if let Some(iters) = benchmark_iterations {
-        match problem_id {
-            Some(id) => {
-                let problem = match project_euler.problem(id) {
-                    Ok(problem) => problem,
-                    Err(e) => {
-                        eprintln!("Error: {e}");
-                        return ExitCode::FAILURE;
-                    }
-                };
-
-                let mut solution = String::new();
-                let mut sample = Sample::new();
-                for _ in 0..iters {
-                    let (result, elapsed) = problem.benchmark();
-                    solution = result;
-                    sample.push(elapsed.as_nanos());
+        let total = Mutex::new(BenchmarkTotal::default());
+        #[cfg(feature = "serde")]
+        let entries = Mutex::new(Vec::new());
+        let benchmark = |problem: &dyn Problem| {
+            let (solution, sample) =
+                problem.benchmark_warmup(warmup_iterations as usize, iters as usize);
+            let result = BenchmarkResult::new(solution, &sample);
+            total.lock().unwrap().add(problem.id(), &result);
+            #[cfg(feature = "serde")]
+            entries.lock().unwrap().push(BenchmarkEntry {
+                id: problem.id(),
+                result: result.clone(),
+            });
+            result
+        };
+        match problem_ids.as_deref() {
+            Some(&[id]) => match project_euler.problem(id) {
+                Ok(problem) => writeln!(out, "{}", format_benchmark(&benchmark(problem), palette))?,
+                Err(e) => {
+                    eprintln!("Error: {e}");
+                    return Ok(ExitCode::FAILURE);
                 }
-                let mut mean = sample.mean().unwrap();
-                let mut stddev = sample.sample_stddev().unwrap();
-                let mut unit = "ns";
-                if mean > 1000.0 {
-                    mean /= 1000.0;
-                    stddev /= 1000.0;
-                    unit = "µs";
+            },
+            Some(ids) => {
+                let problems = select_problems(&project_euler, ids);
+                print_benchmarks(out, options, &problems, slower_than_ns, &benchmark)?;
+            }
+            None => {
+                let problems = project_euler.problems().collect::<Vec<_>>();
+                print_benchmarks(out, options, &problems, slower_than_ns, &benchmark)?;
+            }
+        }
+        if total_flag {
+            let total = total.into_inner().unwrap();
+            writeln!(out, "{}", format_total(&total, palette))?;
+        }
+        #[cfg(feature = "serde")]
+        if let Some(path) = argv.get_one::<PathBuf>("json") {
+            let mut entries = entries.into_inner().unwrap();
+            entries.sort_by_key(|entry| entry.id);
+            if let Err(e) = write_benchmarks(path, &entries) {
+                eprintln!("Error: {e}");
+                out.flush()?;
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+    } else {
+        match problem_ids.as_deref().unwrap() {
+            &[id] => match project_euler.solve(id) {
+                Ok(solution) => writeln!(out, "{}", palette.paint(Color::Answer, solution))?,
+                Err(e) => {
+                    eprintln!("Error: {e}");
+                    return Ok(ExitCode::FAILURE);
                 }
-                if mean > 1000.0 {
-                    mean /= 1000.0;
-                    stddev /= 1000.0;
-                    unit = "ms";
+            },
+            ids => {
+                let problems = select_problems(&project_euler, ids);
+                print_solutions(out, options, &problems)?;
+            }
+        }
+    }
+
+    out.flush()?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Print the identifiers and titles of all available problems.
+/// # Errors
+/// * If writing to `out` fails.
+fn print_list(out: &mut dyn Write, project_euler: &PEuler, palette: Palette) -> io::Result<()> {
+    for problem in project_euler.problems() {
+        match problem.difficulty() {
+            Some(difficulty) => writeln!(
+                out,
+                "Problem {:04}: {} (difficulty: {difficulty}%)",
+                palette.paint(Color::Id, problem.id()),
+                problem.title()
+            )?,
+            None => writeln!(
+                out,
+                "Problem {:04}: {}",
+                palette.paint(Color::Id, problem.id()),
+                problem.title()
+            )?,
+        }
+    }
+    Ok(())
+}
+
+/// Run the interactive loop until `quit` or the end of the input.
+///
+/// Each line is a problem identifier, `list` or `quit`.
+/// The solutions are cached, so repeated queries are answered instantly.
+/// # Arguments
+/// * `project_euler` - The problems, reused across all queries.
+/// * `palette` - The palette for the output.
+/// * `input` - The input with the commands.
+/// * `out` - The writer for the output.
+/// # Errors
+/// * If reading from `input` or writing to `out` fails.
+fn repl(
+    project_euler: &PEuler,
+    palette: Palette,
+    mut input: impl BufRead,
+    out: &mut dyn Write,
+) -> io::Result<()> {
+    writeln!(out, "Enter a problem identifier, `list` or `quit`.")?;
+    let mut line = String::new();
+    loop {
+        write!(out, "> ")?;
+        out.flush()?;
+        line.clear();
+        if input.read_line(&mut line)? == 0 {
+            writeln!(out)?;
+            break;
+        }
+
+        match line.trim() {
+            "" => {}
+            "quit" | "exit" => break,
+            "list" => print_list(out, project_euler, palette)?,
+            command => match command.parse::<usize>() {
+                Ok(id) => {
+                    let start = Instant::now();
+                    match project_euler.solve_cached(id) {
+                        Ok(solution) => {
+                            let (elapsed, unit) =
+                                format_duration(start.elapsed().as_nanos() as f64);
+                            writeln!(
+                                out,
+                                "{} ({elapsed:.3} {unit})",
+                                palette.paint(Color::Answer, solution)
+                            )?;
+                        }
+                        Err(e) => eprintln!("Error: {e}"),
+                    }
                 }
-                if mean > 1000.0 {
-                    mean /= 1000.0;
-                    stddev /= 1000.0;
-                    unit = "s";
+                Err(_) => eprintln!("Error: Unknown command '{command}'."),
+            },
+        }
+    }
+    Ok(())
+}
+
+/// The problems selected by the `PROBLEM` argument.
+#[derive(Clone)]
+enum ProblemSpec {
+    /// The identifiers of the problems.
+    Ids(Vec<usize>),
+    /// The identifiers are read from stdin.
+    Stdin,
+}
+
+/// Parse the `PROBLEM` argument.
+///
+/// The argument is either `-` or a specification for [parse_problem_ids].
+/// # Errors
+/// * If the argument is not `-` and not a valid specification.
+fn parse_problem_spec(spec: &str) -> Result<ProblemSpec, String> {
+    if spec.trim() == "-" {
+        Ok(ProblemSpec::Stdin)
+    } else {
+        parse_problem_ids(spec).map(ProblemSpec::Ids)
+    }
+}
+
+/// Get the identifiers of the problems selected by the arguments.
+/// # Returns
+/// * The sorted identifiers, or `None` if no problems are selected.
+/// # Errors
+/// * If the identifiers cannot be read or are invalid.
+fn selected_ids(argv: &ArgMatches) -> Result<Option<Vec<usize>>, String> {
+    if let Some(path) = argv.get_one::<PathBuf>("from-file") {
+        let file = File::open(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        return read_problem_ids(BufReader::new(file)).map(Some);
+    }
+    match argv.get_one::<ProblemSpec>("problem") {
+        Some(ProblemSpec::Ids(ids)) => Ok(Some(ids.clone())),
+        Some(ProblemSpec::Stdin) => read_problem_ids(stdin().lock()).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Read problem identifiers, with a specification for [parse_problem_ids] on each line.
+///
+/// Empty lines are ignored.
+/// # Arguments
+/// * `input` - The input with the specifications.
+/// # Returns
+/// * The sorted identifiers without duplicates.
+/// # Errors
+/// * If reading fails or a line is not a valid specification.
+fn read_problem_ids(input: impl BufRead) -> Result<Vec<usize>, String> {
+    let mut ids = BTreeSet::new();
+    for (i, line) in input.lines().enumerate() {
+        let line = line.map_err(|e| e.to_string())?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        ids.extend(parse_problem_ids(&line).map_err(|e| format!("line {}: {e}", i + 1))?);
+    }
+    Ok(ids.into_iter().collect())
+}
+
+/// Parse a specification of problem identifiers.
+///
+/// The specification is a comma-separated list of identifiers (`7`)
+/// and inclusive ranges of identifiers (`1-10`).
+/// # Arguments
+/// * `spec` - The specification, e.g. `1-5,9`.
+/// # Returns
+/// * The sorted identifiers without duplicates.
+/// # Errors
+/// * If a part of the specification is not a valid identifier or range.
+fn parse_problem_ids(spec: &str) -> Result<Vec<usize>, String> {
+    let parse_id = |id: &str| match id.trim().parse::<u16>() {
+        Ok(0) | Err(_) => Err(format!("'{}' is not a valid problem identifier", id.trim())),
+        Ok(id) => Ok(usize::from(id)),
+    };
+
+    let mut ids = BTreeSet::new();
+    for part in spec.split(',') {
+        match part.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (parse_id(start)?, parse_id(end)?);
+                if start > end {
+                    return Err(format!("'{}' is an empty range", part.trim()));
                 }
-                println!(
-                    "{solution:20} (iterations: {iters}, mean: {mean:>11.6} {unit:>2}, stddev: {stddev:>11.6} {unit:>2})"
-                );
+                ids.extend(start..=end);
             }
             None => {
-                let max_line_len = project_euler
-                    .problems()
-                    .map(|problem| problem.title().chars().count() + 14)
-                    .max()
-                    .unwrap_or(0);
-                for problem in project_euler.problems() {
-                    print!(
-                        "Problem {:04}: {:width$} => ",
-                        problem.id(),
-                        problem.title(),
-                        width = max_line_len - 14
-                    );
-                    let _ = stdout().flush();
-
-                    let mut solution = String::new();
-                    let mut sample = Sample::new();
-                    for _ in 0..iters {
-                        let (result, elapsed) = problem.benchmark();
-                        solution = result;
-                        sample.push(elapsed.as_nanos());
-                    }
-                    let mut mean = sample.mean().unwrap();
-                    let mut stddev = sample.sample_stddev().unwrap();
-                    let mut unit = "ns";
-                    if mean > 1000.0 {
-                        mean /= 1000.0;
-                        stddev /= 1000.0;
-                        unit = "µs";
-                    }
-                    if mean > 1000.0 {
-                        mean /= 1000.0;
-                        stddev /= 1000.0;
-                        unit = "ms";
-                    }
-                    if mean > 1000.0 {
-                        mean /= 1000.0;
-                        stddev /= 1000.0;
-                        unit = "s";
-                    }
-                    println!(
-                        "{solution:20} (iterations: {iters}, mean: {mean:>11.6} {unit:>2}, stddev: {stddev:>11.6} {unit:>2})"
-                    );
-                }
+                ids.insert(parse_id(part)?);
             }
         }
-    } else {
-        match project_euler.solve(problem_id.unwrap()) {
-            Ok(solution) => println!("{solution}"),
+    }
+
+    Ok(ids.into_iter().collect())
+}
+
+/// Parse a duration with a unit, e.g. `1ms` or `500us`.
+/// # Arguments
+/// * `duration` - The duration with one of the units `ns`, `us` (or `µs`), `ms` and `s`.
+/// # Returns
+/// * The duration in nanoseconds.
+/// # Errors
+/// * If the duration is not a non-negative number followed by a unit.
+fn parse_duration(duration: &str) -> Result<f64, String> {
+    let duration = duration.trim();
+    let (number, scale) = [
+        ("ns", 1.0),
+        ("us", 1e3),
+        ("µs", 1e3),
+        ("ms", 1e6),
+        ("s", 1e9),
+    ]
+    .into_iter()
+    .find_map(|(unit, scale)| duration.strip_suffix(unit).map(|number| (number, scale)))
+    .ok_or_else(|| format!("'{duration}' has no unit (ns, us, ms or s)"))?;
+    match number.trim().parse::<f64>() {
+        Ok(number) if number.is_finite() && number >= 0.0 => Ok(number * scale),
+        _ => Err(format!("'{duration}' is not a valid duration")),
+    }
+}
+
+/// Get the problems with the given identifiers, warning about unavailable ones.
+fn select_problems<'a>(project_euler: &'a PEuler, ids: &[usize]) -> Vec<&'a dyn Problem> {
+    ids.iter()
+        .filter_map(|&id| match project_euler.problem(id) {
+            Ok(problem) => Some(problem),
             Err(e) => {
-                eprintln!("Error: {e}");
-                return ExitCode::FAILURE;
+                eprintln!("Warning: problem {id}: {e}");
+                None
+            }
+        })
+        .collect()
+}
+
+/// Check whether the output should be colorized.
+///
+/// With `auto`, the output is colorized only when it goes to a terminal.
+#[cfg(feature = "color")]
+fn color_enabled(argv: &ArgMatches) -> bool {
+    match argv.get_one::<String>("color").map(String::as_str) {
+        Some("always") => true,
+        Some("never") => false,
+        _ => argv.get_one::<PathBuf>("output").is_none() && stdout().is_terminal(),
+    }
+}
+
+/// Check whether the output should be colorized.
+///
+/// Without the `color` feature, the output is never colorized.
+#[cfg(not(feature = "color"))]
+fn color_enabled(_argv: &ArgMatches) -> bool {
+    false
+}
+
+/// The colors of the parts of the output.
+#[derive(Clone, Copy)]
+enum Color {
+    Id,
+    Answer,
+    Slow,
+    Pass,
+    Fail,
+}
+
+impl Color {
+    /// The ANSI escape sequence which resets the color.
+    const RESET: &'static str = "\x1b[0m";
+
+    /// Get the ANSI escape sequence which sets the color.
+    fn ansi(self) -> &'static str {
+        match self {
+            Color::Id => "\x1b[36m",
+            Color::Answer | Color::Pass => "\x1b[32m",
+            Color::Slow | Color::Fail => "\x1b[31m",
+        }
+    }
+}
+
+/// Colorizes the parts of the output, if enabled.
+#[derive(Clone, Copy)]
+struct Palette {
+    enabled: bool,
+}
+
+impl Palette {
+    /// Colorize the text.
+    /// # Arguments
+    /// * `color` - The color of the text.
+    /// * `text` - The text to colorize.
+    /// # Returns
+    /// * The text which is displayed in the color if the palette is enabled.
+    fn paint<T: Display>(self, color: Color, text: T) -> Painted<T> {
+        Painted {
+            text,
+            color: self.enabled.then_some(color),
+        }
+    }
+}
+
+/// Text which is displayed in a color.
+///
+/// The formatting options (width, precision, ...) are applied to the text itself.
+struct Painted<T> {
+    text: T,
+    color: Option<Color>,
+}
+
+impl<T: Display> Display for Painted<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.color {
+            Some(color) => {
+                f.write_str(color.ansi())?;
+                self.text.fmt(f)?;
+                f.write_str(Color::RESET)
+            }
+            None => self.text.fmt(f),
+        }
+    }
+}
+
+/// Options for printing the results of multiple problems.
+#[derive(Clone, Copy)]
+struct TableOptions {
+    /// The palette for the output.
+    palette: Palette,
+    /// Whether to compute the results in parallel.
+    parallel: bool,
+    /// Whether to print only the results, without the problem prefixes.
+    quiet: bool,
+}
+
+/// Print a row with the result for each problem, aligning the titles.
+/// # Arguments
+/// * `out` - The writer for the rows.
+/// * `options` - The options for the rows.
+/// * `progress` - The progress of the run, advanced after each row.
+/// * `problems` - The problems to print.
+/// * `result` - Writes the result for the problem to the writer.
+/// # Errors
+/// * If writing to `out` fails.
+fn print_table(
+    out: &mut dyn Write,
+    options: TableOptions,
+    progress: &Progress,
+    problems: &[&dyn Problem],
+    mut result: impl FnMut(&dyn Problem, &mut dyn Write) -> io::Result<()>,
+) -> io::Result<()> {
+    let width = problems
+        .iter()
+        .map(|problem| problem.title().chars().count())
+        .max()
+        .unwrap_or(0);
+    for (i, &problem) in problems.iter().enumerate() {
+        if progress.is_visible() {
+            // a partially written row would be overwritten by the progress bar
+            let mut row = Vec::new();
+            print_row(&mut row, options, width, problem, &mut result)?;
+            progress.suspend(|| out.write_all(&row))?;
+        } else {
+            print_row(out, options, width, problem, &mut result)?;
+        }
+        progress.complete(i + 1);
+    }
+    Ok(())
+}
+
+/// Print a row of [print_table].
+fn print_row(
+    out: &mut dyn Write,
+    options: TableOptions,
+    width: usize,
+    problem: &dyn Problem,
+    result: &mut impl FnMut(&dyn Problem, &mut dyn Write) -> io::Result<()>,
+) -> io::Result<()> {
+    if !options.quiet {
+        write!(
+            out,
+            "Problem {:04}: {:width$} => ",
+            options.palette.paint(Color::Id, problem.id()),
+            problem.title()
+        )?;
+        out.flush()?;
+    }
+    result(problem, &mut *out)?;
+    writeln!(out)
+}
+
+/// Print the solutions of the problems with [print_table].
+/// # Arguments
+/// * `out` - The writer for the rows.
+/// * `options` - The options for the rows.
+/// * `problems` - The problems to solve.
+/// # Errors
+/// * If writing to `out` fails.
+fn print_solutions(
+    out: &mut dyn Write,
+    options: TableOptions,
+    problems: &[&dyn Problem],
+) -> io::Result<()> {
+    let palette = options.palette;
+    let progress = Progress::new(problems.len());
+    let printed = if options.parallel {
+        let rows = parallel_rows(problems, &progress, |problem| {
+            palette.paint(Color::Answer, problem.solve()).to_string()
+        });
+        print_table(out, options, &progress, problems, rows)
+    } else {
+        print_table(out, options, &progress, problems, solve_row(palette))
+    };
+    progress.finish();
+    printed
+}
+
+/// Print the results of the problems with [print_table].
+/// # Arguments
+/// * `out` - The writer for the rows.
+/// * `options` - The options for the rows.
+/// * `problems` - The problems to print.
+/// * `result` - Computes and formats the result for the problem, e.g. benchmarks it.
+/// # Errors
+/// * If writing to `out` fails.
+fn print_results(
+    out: &mut dyn Write,
+    options: TableOptions,
+    problems: &[&dyn Problem],
+    result: impl Fn(&dyn Problem) -> String + Sync,
+) -> io::Result<()> {
+    let progress = Progress::new(problems.len());
+    let printed = if options.parallel {
+        let rows = parallel_rows(problems, &progress, result);
+        print_table(out, options, &progress, problems, rows)
+    } else {
+        print_table(out, options, &progress, problems, |problem, out| {
+            write!(out, "{}", result(problem))
+        })
+    };
+    progress.finish();
+    printed
+}
+
+/// Print the benchmark results of the problems with [print_table].
+/// # Arguments
+/// * `out` - The writer for the rows.
+/// * `options` - The options for the rows.
+/// * `problems` - The problems to benchmark.
+/// * `threshold_ns` - If set, only the results with a mean above it (in nanoseconds) are printed.
+/// * `benchmark` - Benchmarks the problem.
+/// # Errors
+/// * If writing to `out` fails.
+fn print_benchmarks(
+    out: &mut dyn Write,
+    options: TableOptions,
+    problems: &[&dyn Problem],
+    threshold_ns: Option<f64>,
+    benchmark: impl Fn(&dyn Problem) -> BenchmarkResult + Sync,
+) -> io::Result<()> {
+    let palette = options.palette;
+    let Some(threshold_ns) = threshold_ns else {
+        return print_results(out, options, problems, |problem| {
+            format_benchmark(&benchmark(problem), palette)
+        });
+    };
+
+    // the rows can only be filtered once all problems are benchmarked
+    let progress = Progress::new(problems.len());
+    let results = compute_all(problems, &progress, options.parallel, benchmark);
+    let (slow_problems, slow_results): (Vec<_>, Vec<_>) =
+        slower_than(problems, results, threshold_ns)
+            .into_iter()
+            .unzip();
+    let mut slow_results = slow_results.into_iter();
+    let printed = print_table(out, options, &progress, &slow_problems, |_, out| {
+        let result = slow_results.next().expect("More rows than results.");
+        write!(out, "{}", format_benchmark(&result, palette))
+    });
+    progress.finish();
+    printed
+}
+
+/// Keep only the benchmark results with a mean above the threshold.
+/// # Arguments
+/// * `problems` - The benchmarked problems.
+/// * `results` - The benchmark results, in the order of the problems.
+/// * `threshold_ns` - The threshold in nanoseconds.
+/// # Returns
+/// * The problems with a mean above the threshold and their results.
+fn slower_than<'a>(
+    problems: &[&'a dyn Problem],
+    results: Vec<BenchmarkResult>,
+    threshold_ns: f64,
+) -> Vec<(&'a dyn Problem, BenchmarkResult)> {
+    problems
+        .iter()
+        .copied()
+        .zip(results)
+        .filter(|(_, result)| result.mean_ns > threshold_ns)
+        .collect()
+}
+
+/// Print whether the solutions of the problems match the known answers with [print_table].
+/// # Arguments
+/// * `out` - The writer for the rows.
+/// * `options` - The options for the rows.
+/// * `problems` - The problems to verify.
+/// # Returns
+/// * The number of problems whose solution does not match the known answer.
+/// # Errors
+/// * If writing to `out` fails.
+fn print_verification(
+    out: &mut dyn Write,
+    options: TableOptions,
+    problems: &[&dyn Problem],
+) -> io::Result<usize> {
+    let palette = options.palette;
+    let failures = AtomicUsize::new(0);
+    print_results(out, options, problems, |problem| {
+        let solution = problem.solve();
+        match problem.answer() {
+            Some(answer) if solution.trim() == answer => {
+                palette.paint(Color::Pass, "PASS").to_string()
             }
+            Some(answer) => {
+                failures.fetch_add(1, Ordering::Relaxed);
+                format!(
+                    "{} (expected: {answer}, got: {solution})",
+                    palette.paint(Color::Fail, "FAIL")
+                )
+            }
+            None => String::from("UNKNOWN (answer not known)"),
+        }
+    })?;
+    Ok(failures.into_inner())
+}
+
+/// Compute the results of all problems, advancing the progress after each result.
+///
+/// The results are computed in parallel if `parallel` is set and the `rayon` feature is enabled.
+/// # Returns
+/// * The results in the order of the problems.
+#[cfg_attr(not(feature = "rayon"), allow(unused_variables))]
+fn compute_all<T: Send>(
+    problems: &[&dyn Problem],
+    progress: &Progress,
+    parallel: bool,
+    result: impl Fn(&dyn Problem) -> T + Sync,
+) -> Vec<T> {
+    let compute = |&problem: &&dyn Problem| {
+        let result = result(problem);
+        progress.inc();
+        result
+    };
+    #[cfg(feature = "rayon")]
+    if parallel {
+        return problems.par_iter().map(compute).collect();
+    }
+    problems.iter().map(compute).collect()
+}
+
+/// Compute the results of all problems and create a row writer for [print_table]
+/// which writes them in the order of the problems.
+///
+/// The results are computed in parallel if the `rayon` feature is enabled.
+/// The progress is advanced after each computed result.
+fn parallel_rows(
+    problems: &[&dyn Problem],
+    progress: &Progress,
+    result: impl Fn(&dyn Problem) -> String + Sync,
+) -> impl FnMut(&dyn Problem, &mut dyn Write) -> io::Result<()> {
+    let mut results = compute_all(problems, progress, true, result).into_iter();
+    move |_: &dyn Problem, out: &mut dyn Write| {
+        let result = results.next().expect("More rows than results.");
+        write!(out, "{result}")
+    }
+}
+
+/// Shows the progress of a run over multiple problems on stderr.
+///
+/// The progress bar is shown only if the `progress` feature is enabled
+/// and both stdout and stderr are terminals.
+struct Progress {
+    #[cfg(feature = "progress")]
+    bar: ProgressBar,
+}
+
+#[cfg(feature = "progress")]
+impl Progress {
+    /// Create a new progress for the number of problems.
+    fn new(len: usize) -> Self {
+        let bar = if stdout().is_terminal() && stderr().is_terminal() {
+            ProgressBar::new(len as u64)
+        } else {
+            ProgressBar::hidden()
+        };
+        Self { bar }
+    }
+
+    /// Check whether the progress bar is shown.
+    fn is_visible(&self) -> bool {
+        !self.bar.is_hidden()
+    }
+
+    /// Mark one more problem as completed.
+    fn inc(&self) {
+        self.bar.inc(1);
+    }
+
+    /// Mark at least `n` problems as completed.
+    fn complete(&self, n: usize) {
+        if self.bar.position() < n as u64 {
+            self.bar.set_position(n as u64);
         }
     }
 
-    ExitCode::SUCCESS
+    /// Hide the progress bar while running the function.
+    fn suspend<R>(&self, f: impl FnOnce() -> R) -> R {
+        self.bar.suspend(f)
+    }
+
+    /// Remove the progress bar.
+    fn finish(&self) {
+        self.bar.finish_and_clear();
+    }
+}
+
+#[cfg(not(feature = "progress"))]
+impl Progress {
+    fn new(_len: usize) -> Self {
+        Self {}
+    }
+
+    fn is_visible(&self) -> bool {
+        false
+    }
+
+    fn inc(&self) {}
+
+    fn complete(&self, _n: usize) {}
+
+    fn suspend<R>(&self, f: impl FnOnce() -> R) -> R {
+        f()
+    }
+
+    fn finish(&self) {}
+}
+
+/// Create a row writer for [print_table] which solves the problem.
+///
+/// The solutions are written into a reused buffer to avoid allocations.
+fn solve_row(palette: Palette) -> impl FnMut(&dyn Problem, &mut dyn Write) -> io::Result<()> {
+    let mut solution = String::new();
+    move |problem: &dyn Problem, out: &mut dyn Write| {
+        problem.solve_into(&mut solution);
+        write!(out, "{}", palette.paint(Color::Answer, &solution))
+    }
+}
+
+/// Format the benchmark result using the most suitable time unit.
+///
+/// Slow means are colorized with [Color::Slow].
+fn format_benchmark(result: &BenchmarkResult, palette: Palette) -> String {
+    let (mean, mean_unit) = format_duration(result.mean_ns);
+    let (stddev, stddev_unit) = format_duration(result.stddev_ns);
+    let mean = Palette {
+        enabled: palette.enabled && result.mean_ns >= SLOW_NS,
+    }
+    .paint(Color::Slow, mean);
+    format!(
+        "{:20} (iterations: {}, mean: {mean:>11.6} {mean_unit:>2}, stddev: {stddev:>11.6} {stddev_unit:>2})",
+        palette.paint(Color::Answer, &result.answer),
+        result.iterations
+    )
+}
+
+/// The aggregate of the benchmark results of multiple problems.
+#[derive(Debug, Default, PartialEq)]
+struct BenchmarkTotal {
+    /// The sum of the mean elapsed times, in nanoseconds.
+    mean_ns: f64,
+    /// The identifier and the mean elapsed time of the slowest problem.
+    slowest: Option<(usize, f64)>,
+}
+
+impl BenchmarkTotal {
+    /// Add the benchmark result of a problem to the aggregate.
+    /// # Arguments
+    /// * `id` - The identifier of the problem.
+    /// * `result` - The benchmark result of the problem.
+    fn add(&mut self, id: usize, result: &BenchmarkResult) {
+        self.mean_ns += result.mean_ns;
+        if self
+            .slowest
+            .is_none_or(|(_, slowest_ns)| result.mean_ns > slowest_ns)
+        {
+            self.slowest = Some((id, result.mean_ns));
+        }
+    }
+}
+
+/// Format the aggregate of the benchmark results using the most suitable time units.
+fn format_total(total: &BenchmarkTotal, palette: Palette) -> String {
+    let (mean, unit) = format_duration(total.mean_ns);
+    match total.slowest {
+        Some((id, slowest_ns)) => {
+            let (slowest, slowest_unit) = format_duration(slowest_ns);
+            format!(
+                "Total: {mean:.6} {unit} (slowest: Problem {:04}, {slowest:.6} {slowest_unit})",
+                palette.paint(Color::Id, id)
+            )
+        }
+        None => format!("Total: {mean:.6} {unit}"),
+    }
+}
+
+/// An entry of a JSON benchmark export.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct BenchmarkEntry {
+    /// The identifier of the problem.
+    id: usize,
+    /// The benchmark result of the problem.
+    #[serde(flatten)]
+    result: BenchmarkResult,
+}
+
+/// Write a JSON benchmark export.
+///
+/// The export is an array of objects with the `id` of the problem
+/// and the fields of its [BenchmarkResult].
+/// # Arguments
+/// * `path` - The path to the export.
+/// * `entries` - The benchmarked problems.
+/// # Errors
+/// * If the file cannot be written.
+#[cfg(feature = "serde")]
+fn write_benchmarks(path: &Path, entries: &[BenchmarkEntry]) -> Result<(), String> {
+    let file = File::create(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    let mut writer = BufWriter::new(file);
+    serde_json::to_writer_pretty(&mut writer, entries)
+        .map_err(|e| format!("{}: {e}", path.display()))?;
+    writer
+        .flush()
+        .map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Read a JSON benchmark export written by [write_benchmarks].
+/// # Arguments
+/// * `path` - The path to the export.
+/// # Returns
+/// * The mean elapsed times in nanoseconds, by problem identifier.
+/// # Errors
+/// * If the file cannot be read or is not a valid export.
+#[cfg(feature = "serde")]
+fn read_benchmarks(path: &Path) -> Result<BTreeMap<usize, f64>, String> {
+    let file = File::open(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    let entries: Vec<BenchmarkEntry> = serde_json::from_reader(BufReader::new(file))
+        .map_err(|e| format!("{}: {e}", path.display()))?;
+    Ok(entries
+        .into_iter()
+        .map(|entry| (entry.id, entry.result.mean_ns))
+        .collect())
+}
+
+/// Run the `compare` subcommand, writing the comparison to `out`.
+/// # Errors
+/// * If writing to `out` fails.
+#[cfg(feature = "serde")]
+fn compare(argv: &ArgMatches, palette: Palette, out: &mut dyn Write) -> io::Result<ExitCode> {
+    let baseline = argv.get_one::<PathBuf>("baseline").unwrap();
+    let current = argv.get_one::<PathBuf>("current").unwrap();
+    let benchmarks = read_benchmarks(baseline)
+        .and_then(|baseline| read_benchmarks(current).map(|current| (baseline, current)));
+    match benchmarks {
+        Ok((baseline, current)) => print_comparison(out, palette, &baseline, &current)?,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return Ok(ExitCode::FAILURE);
+        }
+    }
+    out.flush()?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Print the relative change of the mean times of each problem and of all problems.
+/// # Arguments
+/// * `out` - The writer for the comparison.
+/// * `palette` - The palette for the output.
+/// * `baseline` - The baseline mean times in nanoseconds, by problem identifier.
+/// * `current` - The current mean times in nanoseconds, by problem identifier.
+/// # Errors
+/// * If writing to `out` fails.
+#[cfg(feature = "serde")]
+fn print_comparison(
+    out: &mut dyn Write,
+    palette: Palette,
+    baseline: &BTreeMap<usize, f64>,
+    current: &BTreeMap<usize, f64>,
+) -> io::Result<()> {
+    let ids = baseline
+        .keys()
+        .chain(current.keys())
+        .collect::<BTreeSet<_>>();
+    let (mut baseline_total, mut current_total) = (0.0, 0.0);
+    for id in ids {
+        let id_painted = palette.paint(Color::Id, id);
+        match (baseline.get(id), current.get(id)) {
+            (Some(&baseline_ns), Some(&current_ns)) => {
+                baseline_total += baseline_ns;
+                current_total += current_ns;
+                writeln!(
+                    out,
+                    "Problem {id_painted:04}: {}",
+                    format_change(baseline_ns, current_ns, palette)
+                )?;
+            }
+            (Some(_), None) => writeln!(out, "Problem {id_painted:04}: only in the baseline")?,
+            (None, _) => writeln!(out, "Problem {id_painted:04}: only in the current run")?,
+        }
+    }
+    writeln!(
+        out,
+        "Total: {}",
+        format_change(baseline_total, current_total, palette)
+    )
+}
+
+/// Format the change from the baseline to the current mean time.
+///
+/// Negative changes are speedups and positive changes are slowdowns.
+/// The change is `n/a` if the baseline is `0`, e.g. the total of two runs without common problems.
+#[cfg(feature = "serde")]
+fn format_change(baseline_ns: f64, current_ns: f64, palette: Palette) -> String {
+    let (baseline, baseline_unit) = format_duration(baseline_ns);
+    let (current, current_unit) = format_duration(current_ns);
+    if baseline_ns == 0.0 {
+        return format!("{baseline:.3} {baseline_unit} -> {current:.3} {current_unit} (n/a)");
+    }
+    let change = (current_ns - baseline_ns) / baseline_ns * 100.0;
+    let change = if change > 0.0 {
+        palette.paint(Color::Slow, format!("{change:+.2}%"))
+    } else {
+        palette.paint(Color::Pass, format!("{change:+.2}%"))
+    };
+    format!("{baseline:.3} {baseline_unit} -> {current:.3} {current_unit} ({change})")
+}
+
+/// Scale the duration to the most suitable time unit.
+///
+/// The smallest unit in which the duration does not exceed 1000 is used,
+/// with seconds as the largest unit.
+/// # Arguments
+/// * `ns` - The duration in nanoseconds.
+/// # Returns
+/// * The duration in the unit and the symbol of the unit.
+fn format_duration(ns: f64) -> (f64, &'static str) {
+    let mut duration = ns;
+    let mut unit = "ns";
+    for next_unit in ["µs", "ms", "s"] {
+        if duration <= 1000.0 {
+            break;
+        }
+        duration /= 1000.0;
+        unit = next_unit;
+    }
+    (duration, unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use peuler::Solution;
+    use pmath::statistics::Sample;
+
+    struct StubProblem(usize);
+    impl Problem for StubProblem {
+        fn id(&self) -> usize {
+            self.0
+        }
+
+        fn title(&self) -> &str {
+            "Stub"
+        }
+    }
+    impl Solution for StubProblem {
+        fn solve(&self) -> String {
+            self.0.to_string()
+        }
+    }
+
+    #[test]
+    fn benchmark_total() {
+        //! Test that [BenchmarkTotal] sums the means and finds the slowest problem.
+
+        let results = [
+            (
+                1,
+                BenchmarkResult::new(String::from("1"), &Sample::from_iter([10, 20, 30])),
+            ),
+            (
+                2,
+                BenchmarkResult::new(String::from("2"), &Sample::from_iter([400, 500])),
+            ),
+            (
+                3,
+                BenchmarkResult::new(String::from("3"), &Sample::from_iter([7, 7, 7, 7])),
+            ),
+        ];
+
+        let mut total = BenchmarkTotal::default();
+        assert_eq!(total.slowest, None);
+        for (id, result) in &results {
+            total.add(*id, result);
+        }
+        assert_eq!(total.mean_ns, 20.0 + 450.0 + 7.0);
+        assert_eq!(
+            total.mean_ns,
+            results
+                .iter()
+                .map(|(_, result)| result.mean_ns)
+                .sum::<f64>()
+        );
+        assert_eq!(total.slowest, Some((2, 450.0)));
+    }
+
+    #[test]
+    fn slower_than_threshold() {
+        //! Test that [slower_than] keeps only the results with a mean above the threshold.
+
+        let stubs = [StubProblem(1), StubProblem(2), StubProblem(3)];
+        let problems = stubs
+            .iter()
+            .map(|stub| stub as &dyn Problem)
+            .collect::<Vec<_>>();
+        let results = [[400, 600], [1_500_000, 2_500_000], [1_000_000, 1_000_000]]
+            .into_iter()
+            .zip(&problems)
+            .map(|(sample, problem)| {
+                BenchmarkResult::new(problem.solve(), &Sample::from_iter(sample))
+            })
+            .collect();
+
+        let slow = slower_than(&problems, results, parse_duration("1ms").unwrap());
+        assert_eq!(slow.len(), 1);
+        assert_eq!(slow[0].0.id(), 2);
+        assert_eq!(slow[0].1.mean_ns, 2_000_000.0);
+    }
+
+    #[test]
+    fn parse_duration_units() {
+        //! Test [parse_duration] with all units.
+
+        assert_eq!(parse_duration("250ns"), Ok(250.0));
+        assert_eq!(parse_duration("500us"), Ok(500_000.0));
+        assert_eq!(parse_duration("500µs"), Ok(500_000.0));
+        assert_eq!(parse_duration("1ms"), Ok(1_000_000.0));
+        assert_eq!(parse_duration("1.5s"), Ok(1_500_000_000.0));
+        assert_eq!(parse_duration(" 2 ms "), Ok(2_000_000.0));
+        assert_eq!(parse_duration("0s"), Ok(0.0));
+    }
+
+    #[test]
+    fn parse_duration_invalid() {
+        //! Test that [parse_duration] rejects invalid durations.
+
+        for duration in ["", "1", "ms", "-1ms", "1h", "1 m s", "infs", "NaNms"] {
+            assert!(parse_duration(duration).is_err(), "{duration}");
+        }
+    }
+
+    #[test]
+    fn format_duration_boundaries() {
+        //! Test [format_duration] around the boundaries of the units.
+
+        let assert_duration = |ns: f64, expected: f64, expected_unit: &str| {
+            let (duration, unit) = format_duration(ns);
+            assert!((duration - expected).abs() < 1e-9, "{ns}: {duration}");
+            assert_eq!(unit, expected_unit, "{ns}");
+        };
+
+        assert_duration(0.0, 0.0, "ns");
+        assert_duration(999.0, 999.0, "ns");
+        assert_duration(1000.0, 1000.0, "ns");
+        assert_duration(1001.0, 1.001, "µs");
+        assert_duration(999_999.0, 999.999, "µs");
+        assert_duration(1_000_000.0, 1000.0, "µs");
+        assert_duration(1_000_001.0, 1.000001, "ms");
+        assert_duration(1e9, 1000.0, "ms");
+        assert_duration(1.5e9, 1.5, "s");
+        assert_duration(5e12, 5000.0, "s");
+    }
+
+    #[test]
+    fn read_problem_ids_lines() {
+        //! Test [read_problem_ids] with identifiers and ranges on separate lines.
+
+        let input = "5\n1-3\n\n  2 \n9,7\n";
+        assert_eq!(
+            read_problem_ids(input.as_bytes()),
+            Ok(vec![1, 2, 3, 5, 7, 9])
+        );
+        assert_eq!(read_problem_ids("".as_bytes()), Ok(Vec::new()));
+        assert!(read_problem_ids("1\nfoo\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn parse_problem_ids_single() {
+        //! Test [parse_problem_ids] with a single identifier.
+
+        assert_eq!(parse_problem_ids("7"), Ok(vec![7]));
+        assert_eq!(parse_problem_ids(" 12 "), Ok(vec![12]));
+    }
+
+    #[test]
+    fn parse_problem_ids_ranges() {
+        //! Test [parse_problem_ids] with ranges.
+
+        assert_eq!(parse_problem_ids("1-5"), Ok(vec![1, 2, 3, 4, 5]));
+        assert_eq!(parse_problem_ids("3-3"), Ok(vec![3]));
+        assert_eq!(parse_problem_ids("8-10,1-2"), Ok(vec![1, 2, 8, 9, 10]));
+    }
+
+    #[test]
+    fn parse_problem_ids_lists() {
+        //! Test [parse_problem_ids] with lists.
+
+        assert_eq!(parse_problem_ids("1,2,5"), Ok(vec![1, 2, 5]));
+        assert_eq!(parse_problem_ids("5, 1, 2"), Ok(vec![1, 2, 5]));
+        assert_eq!(parse_problem_ids("1-5,9"), Ok(vec![1, 2, 3, 4, 5, 9]));
+    }
+
+    #[test]
+    fn parse_problem_ids_overlaps() {
+        //! Test that [parse_problem_ids] removes duplicates.
+
+        assert_eq!(parse_problem_ids("1-4,3-6"), Ok(vec![1, 2, 3, 4, 5, 6]));
+        assert_eq!(parse_problem_ids("2,1-3,2"), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn parse_problem_ids_invalid() {
+        //! Test that [parse_problem_ids] rejects invalid specifications.
+
+        for spec in [
+            "", "0", "a", "1,", "1-", "-3", "5-1", "1-2-3", "0-4", "70000",
+        ] {
+            assert!(parse_problem_ids(spec).is_err(), "{spec}");
+        }
+    }
 }
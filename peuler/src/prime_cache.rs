@@ -0,0 +1,141 @@
+//! A process-wide cache of primes shared by the problem solvers.
+//!
+//! Many problems need all primes below some limit, so instead of each solver
+//! running its own sieve, the primes are sieved once and reused.
+//! The cache starts empty and grows on demand: when a solver asks for more primes
+//! than are cached, the sieve is rerun up to at least twice the previous bound,
+//! so a sequence of growing requests only sieves a logarithmic number of times.
+//! The bound never exceeds [MAX_BOUND], and only shrinks when the cache is [cleared](clear)
+//! before a timed run, so that benchmarks measure the sieve instead of a lookup.
+
+use pmath::primes::sieve_of_eratosthenes;
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+/// The largest bound the cache grows to.
+///
+/// Primes up to `10^7` take about 5 MB. Larger requests are sieved
+/// for the caller alone, without being stored.
+pub(crate) const MAX_BOUND: u64 = 10_000_000;
+
+/// All primes less than or equal to the bound.
+struct PrimeCache {
+    bound: u64,
+    primes: Vec<u64>,
+}
+impl PrimeCache {
+    /// The cached primes less than or equal to `limit`, which must not exceed the bound.
+    fn primes_up_to(&self, limit: u64) -> &[u64] {
+        &self.primes[..self.primes.partition_point(|&prime| prime <= limit)]
+    }
+
+    /// Sieve again so that the bound is at least `limit`.
+    fn grow(&mut self, limit: u64) {
+        self.bound = limit.max(self.bound.saturating_mul(2)).min(MAX_BOUND);
+        self.primes = sieve_of_eratosthenes(self.bound);
+    }
+}
+
+/// The shared cache, created empty on first use.
+fn cache() -> &'static RwLock<PrimeCache> {
+    static CACHE: OnceLock<RwLock<PrimeCache>> = OnceLock::new();
+    CACHE.get_or_init(|| {
+        RwLock::new(PrimeCache {
+            bound: 0,
+            primes: Vec::new(),
+        })
+    })
+}
+
+/// Call `f` with all primes less than or equal to `limit`, in ascending order.
+///
+/// The primes are borrowed from the shared cache, which is grown first if needed.
+/// If `limit` exceeds [MAX_BOUND], the primes are sieved just for this call.
+pub(crate) fn with_primes<R>(limit: u64, f: impl FnOnce(&[u64]) -> R) -> R {
+    if limit > MAX_BOUND {
+        return f(&sieve_of_eratosthenes(limit));
+    }
+
+    loop {
+        {
+            let cache = cache().read().unwrap_or_else(PoisonError::into_inner);
+            if cache.bound >= limit {
+                return f(cache.primes_up_to(limit));
+            }
+        }
+        // another thread might have grown the cache in the meantime,
+        // or cleared it again before the read lock is taken, hence the loop
+        let mut cache = cache().write().unwrap_or_else(PoisonError::into_inner);
+        if cache.bound < limit {
+            cache.grow(limit);
+        }
+    }
+}
+
+/// Empty the shared cache, so that the next call to [with_primes] sieves again.
+pub(crate) fn clear() {
+    let mut cache = cache().write().unwrap_or_else(PoisonError::into_inner);
+    cache.bound = 0;
+    cache.primes = Vec::new();
+}
+
+/// The current bound of the shared cache.
+#[cfg(test)]
+fn bound() -> u64 {
+    cache().read().unwrap_or_else(PoisonError::into_inner).bound
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problems::{Problem0010, Problem0050};
+    use crate::{Problem, Solution};
+    use std::sync::Mutex;
+
+    /// Serializes the tests which depend on the bound, since [clear] shrinks it.
+    static BOUND_LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn with_primes_limits() {
+        //! Test that [with_primes] gives exactly the primes up to the limit
+
+        let _guard = BOUND_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        for limit in [0, 1, 2, 10, 100, 1000] {
+            let expected = sieve_of_eratosthenes(limit);
+            with_primes(limit, |primes| assert_eq!(primes, expected));
+        }
+        with_primes(MAX_BOUND + 100, |primes| {
+            assert_eq!(primes.last(), Some(&10_000_079))
+        });
+    }
+
+    #[test]
+    fn shared_between_problems() {
+        //! Test that problems using the cache are solved correctly and the cache only grows
+
+        let _guard = BOUND_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut previous = bound();
+        for problem in [&Problem0050::new() as &dyn Problem, &Problem0010::new()] {
+            assert_eq!(Some(problem.solve().as_str()), problem.answer());
+            let current = bound();
+            assert!(
+                current >= previous,
+                "cache shrank from {previous} to {current}"
+            );
+            previous = current;
+        }
+        assert!(previous >= 1_999_999);
+        assert!(previous <= MAX_BOUND);
+    }
+
+    #[test]
+    fn clear_resets() {
+        //! Test that [clear] empties the cache and that it is refilled afterwards
+
+        let _guard = BOUND_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        with_primes(1000, |_| ());
+        clear();
+        assert_eq!(bound(), 0);
+        with_primes(100, |primes| assert_eq!(primes, sieve_of_eratosthenes(100)));
+        assert!(bound() >= 100);
+    }
+}
@@ -1,7 +1,13 @@
 //! [*Project Euler*](https://projecteuler.net) problems with solutions.
 
 macro_rules! problem {
-    ($struct_name:ident, $id:literal, $title:literal) => {
+    (
+        $struct_name:ident,
+        $id:literal,
+        $title:literal
+        $(, $answer:literal $(, $difficulty:literal)?)?
+        $(, [$($tag:literal),* $(,)?])?
+    ) => {
         #[doc = concat!("[*", $title, "*](https://projecteuler.net/problem=", $id, ")")]
         #[derive(Copy, Clone)]
         pub struct $struct_name {
@@ -30,6 +36,21 @@ macro_rules! problem {
             fn title(&self) -> &str {
                 self.title
             }
+            $(
+                fn answer(&self) -> Option<&'static str> {
+                    Some($answer)
+                }
+                $(
+                    fn difficulty(&self) -> Option<u8> {
+                        Some($difficulty)
+                    }
+                )?
+            )?
+            $(
+                fn tags(&self) -> &'static [&'static str] {
+                    &[$($tag),*]
+                }
+            )?
         }
     };
 }
@@ -299,3 +320,57 @@ pub use p0099::Problem0099;
 pub use p0100::Problem0100;
 #[doc(inline)]
 pub use p0102::Problem0102;
+
+#[cfg(test)]
+mod tests {
+    use crate::{Problem, Solution};
+
+    problem!(BareProblem, 9998, "Bare Problem");
+    impl Solution for BareProblem {
+        fn solve(&self) -> String {
+            String::new()
+        }
+    }
+
+    problem!(FullProblem, 9999, "Full Problem", "42", 35);
+    impl Solution for FullProblem {
+        fn solve(&self) -> String {
+            String::from("42")
+        }
+    }
+
+    problem!(
+        TaggedProblem,
+        10000,
+        "Tagged Problem",
+        ["primes", "sequences"]
+    );
+    impl Solution for TaggedProblem {
+        fn solve(&self) -> String {
+            String::new()
+        }
+    }
+
+    #[test]
+    fn macro_metadata() {
+        //! Test that the metadata given to the `problem!` macro is returned by [Problem]
+
+        let problem = BareProblem::new();
+        assert_eq!(problem.id(), 9998);
+        assert_eq!(problem.title(), "Bare Problem");
+        assert_eq!(problem.answer(), None);
+        assert_eq!(problem.difficulty(), None);
+        assert!(problem.tags().is_empty());
+
+        let problem = FullProblem::new();
+        assert_eq!(problem.id(), 9999);
+        assert_eq!(problem.title(), "Full Problem");
+        assert_eq!(problem.answer(), Some("42"));
+        assert_eq!(problem.difficulty(), Some(35));
+        assert!(problem.tags().is_empty());
+
+        let problem = TaggedProblem::new();
+        assert_eq!(problem.answer(), None);
+        assert_eq!(problem.tags(), &["primes", "sequences"]);
+    }
+}
@@ -1,7 +1,14 @@
 use crate::Solution;
 use pmath::sequences::FibonacciSeq;
 
-problem!(Problem0002, 2, "Even Fibonacci Numbers");
+problem!(
+    Problem0002,
+    2,
+    "Even Fibonacci Numbers",
+    "4613732",
+    5,
+    ["fibonacci"]
+);
 
 impl Solution for Problem0002 {
     fn solve(&self) -> String {
@@ -1,11 +1,53 @@
-use crate::Solution;
-use pmath::factors::prime_factors;
+use crate::{ParametrizedSolution, Solution, SolutionValue};
+use malachite::Integer;
+use pmath::factors::factorize;
+use std::fmt::Write;
 
-problem!(Problem0003, 3, "Largest Prime Factor");
+problem!(
+    Problem0003,
+    3,
+    "Largest Prime Factor",
+    "6857",
+    5,
+    ["primes"]
+);
+
+/// The number from the problem.
+const TARGET: u64 = 600851475143;
 
 impl Solution for Problem0003 {
     fn solve(&self) -> String {
-        const TARGET: u64 = 600851475143;
-        prime_factors(TARGET).max().unwrap().to_string()
+        self.solve_typed().to_string()
+    }
+
+    fn solve_into(&self, buf: &mut String) {
+        buf.clear();
+        write!(buf, "{}", largest_prime_factor(TARGET)).expect("Cannot write to String.");
     }
+
+    fn solve_typed(&self) -> SolutionValue {
+        SolutionValue::Integer(Integer::from(largest_prime_factor(TARGET)))
+    }
+}
+
+impl ParametrizedSolution for Problem0003 {
+    /// The number to find the largest prime factor of, at least `2`.
+    type Params = u64;
+
+    fn solve_with(&self, n: Self::Params) -> String {
+        largest_prime_factor(n).to_string()
+    }
+}
+
+/// Find the largest prime factor of `n`.
+///
+/// The factorization uses Pollard's rho, so even products of two large primes
+/// are factorized quickly.
+fn largest_prime_factor(n: u64) -> u64 {
+    if n < 2 {
+        panic!("n must be greater than or equal to 2.");
+    }
+
+    // the factors are in ascending order, so the last one is the largest
+    factorize(n).last().unwrap().0
 }
@@ -1,17 +1,47 @@
-use crate::Solution;
+use crate::{ParametrizedSolution, Solution};
 use pmath::digits::is_palindrome;
 
-problem!(Problem0004, 4, "Largest Palindrome Product");
+problem!(
+    Problem0004,
+    4,
+    "Largest Palindrome Product",
+    "906609",
+    5,
+    ["palindromes"]
+);
 
 impl Solution for Problem0004 {
     fn solve(&self) -> String {
-        let mut largest_palindrome: u64 = 0;
+        self.solve_with(3)
+    }
+}
+
+impl ParametrizedSolution for Problem0004 {
+    /// The number of digits of both factors, from `1` to `9`.
+    type Params = u32;
+
+    fn solve_with(&self, digits: Self::Params) -> String {
+        let smallest = 10_u64.pow(digits - 1);
+        let largest = 10_u64.pow(digits) - 1;
 
-        for fact1 in 100..1000 {
-            for fact2 in fact1..1000 {
+        let mut largest_palindrome = 0;
+
+        // go through the factor pairs from the largest products down,
+        // with fact1 <= fact2 so that each pair is checked once
+        for fact1 in (smallest..=largest).rev() {
+            // the remaining products are at most fact1 * largest
+            if fact1 * largest <= largest_palindrome {
+                break;
+            }
+            for fact2 in (fact1..=largest).rev() {
                 let product = fact1 * fact2;
-                if is_palindrome(product, 10) && (product > largest_palindrome) {
+                if product <= largest_palindrome {
+                    break;
+                }
+                if is_palindrome(product, 10) {
+                    // smaller fact2 only give smaller products
                     largest_palindrome = product;
+                    break;
                 }
             }
         }
@@ -1,10 +1,19 @@
-use crate::Solution;
+use crate::{ParametrizedSolution, Solution};
 use pmath::lcm_multiple;
 
-problem!(Problem0005, 5, "Smallest Multiple");
+problem!(Problem0005, 5, "Smallest Multiple", "232792560", 5);
 
 impl Solution for Problem0005 {
     fn solve(&self) -> String {
-        lcm_multiple(1u32..=20).to_string()
+        self.solve_with(20)
+    }
+}
+
+impl ParametrizedSolution for Problem0005 {
+    /// The largest number that must divide the multiple.
+    type Params = u64;
+
+    fn solve_with(&self, n: Self::Params) -> String {
+        lcm_multiple(1..=n).to_string()
     }
 }
@@ -1,12 +1,21 @@
-use crate::Solution;
+use crate::{ParametrizedSolution, Solution};
 use pmath::sequences::{NatNumSeq, NatNumSqSeq, Sequence};
 
-problem!(Problem0006, 6, "Sum Square Difference");
+problem!(Problem0006, 6, "Sum Square Difference", "25164150", 5);
 
 impl Solution for Problem0006 {
     fn solve(&self) -> String {
-        let sum_of_squares = NatNumSqSeq::<i32>::new().sum_next_n(100);
-        let square_of_sum = NatNumSeq::<i32>::new().sum_next_n(100).pow(2);
+        self.solve_with(100)
+    }
+}
+
+impl ParametrizedSolution for Problem0006 {
+    /// The number of first natural numbers.
+    type Params = usize;
+
+    fn solve_with(&self, n: Self::Params) -> String {
+        let sum_of_squares = NatNumSqSeq::<u64>::new().sum_next_n(n);
+        let square_of_sum = NatNumSeq::<u64>::new().sum_next_n(n).pow(2);
 
         sum_of_squares.abs_diff(square_of_sum).to_string()
     }
@@ -1,10 +1,11 @@
 use crate::Solution;
-use pmath::primes::{apcf, sieve_of_eratosthenes};
+use crate::prime_cache::with_primes;
+use pmath::primes::apcf;
 
-problem!(Problem0007, 7, "10001st Prime");
+problem!(Problem0007, 7, "10001st Prime", "104743", 5, ["primes"]);
 
 impl Solution for Problem0007 {
     fn solve(&self) -> String {
-        sieve_of_eratosthenes(apcf(10001u64).round() as u64)[10_000].to_string()
+        with_primes(apcf(10001u64).round() as u64, |primes| primes[10_000]).to_string()
     }
 }
@@ -1,6 +1,12 @@
 use crate::Solution;
 
-problem!(Problem0008, 8, "Largest Product in a Series");
+problem!(
+    Problem0008,
+    8,
+    "Largest Product in a Series",
+    "23514624000",
+    5
+);
 
 impl Solution for Problem0008 {
     fn solve(&self) -> String {
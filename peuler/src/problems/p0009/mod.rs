@@ -1,6 +1,13 @@
 use crate::Solution;
 
-problem!(Problem0009, 9, "Special Pythagorean Triplet");
+problem!(
+    Problem0009,
+    9,
+    "Special Pythagorean Triplet",
+    "31875000",
+    5,
+    ["pythagorean-triples"]
+);
 
 impl Solution for Problem0009 {
     fn solve(&self) -> String {
@@ -1,14 +1,26 @@
-use crate::Solution;
-use pmath::primes::sieve_of_eratosthenes;
+use crate::prime_cache::with_primes;
+use crate::{ParametrizedSolution, Solution};
 
-problem!(Problem0010, 10, "Summation of Primes");
+problem!(
+    Problem0010,
+    10,
+    "Summation of Primes",
+    "142913828922",
+    5,
+    ["primes"]
+);
 
 impl Solution for Problem0010 {
     fn solve(&self) -> String {
-        const LIMIT: u64 = 2_000_000;
-        sieve_of_eratosthenes(LIMIT - 1)
-            .into_iter()
-            .sum::<u64>()
-            .to_string()
+        self.solve_with(2_000_000)
+    }
+}
+
+impl ParametrizedSolution for Problem0010 {
+    /// The upper limit (exclusive) for the primes.
+    type Params = u64;
+
+    fn solve_with(&self, limit: Self::Params) -> String {
+        with_primes(limit.saturating_sub(1), |primes| primes.iter().sum::<u64>()).to_string()
     }
 }
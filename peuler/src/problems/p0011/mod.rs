@@ -1,6 +1,6 @@
 use crate::Solution;
 
-problem!(Problem0011, 11, "Largest Product in a Grid");
+problem!(Problem0011, 11, "Largest Product in a Grid", "70600674", 5);
 
 impl Solution for Problem0011 {
     fn solve(&self) -> String {
@@ -1,7 +1,13 @@
 use crate::Solution;
-use pmath::factors::divisors;
+use pmath::factors::num_divisors;
 
-problem!(Problem0012, 12, "Highly Divisible Triangular Number");
+problem!(
+    Problem0012,
+    12,
+    "Highly Divisible Triangular Number",
+    "76576500",
+    5
+);
 
 impl Solution for Problem0012 {
     fn solve(&self) -> String {
@@ -28,16 +34,16 @@ impl Solution for Problem0012 {
         // let's start from n = 5
         let mut n: u32 = 5;
         // since 5 is odd, we can calculate initial values of d_t1 and d_t2
-        let mut d_t1 = divisors(n).count() as i32;
-        let mut d_t2 = divisors(n.div_ceil(2)).count() as i32;
+        let mut d_t1 = num_divisors(n);
+        let mut d_t2 = num_divisors(n.div_ceil(2));
 
         while d_t1 * d_t2 <= 500 {
             n += 1;
             d_t1 = d_t2;
             if n.is_multiple_of(2) {
-                d_t2 = divisors(n + 1).count() as i32;
+                d_t2 = num_divisors(n + 1);
             } else {
-                d_t2 = divisors(n.div_ceil(2)).count() as i32;
+                d_t2 = num_divisors(n.div_ceil(2));
             }
         }
 
@@ -2,7 +2,7 @@ use crate::Solution;
 use malachite::Natural;
 use std::str::FromStr;
 
-problem!(Problem0013, 13, "Large Sum");
+problem!(Problem0013, 13, "Large Sum", "5537376230", 5);
 
 impl Solution for Problem0013 {
     fn solve(&self) -> String {
@@ -1,13 +1,39 @@
-use crate::Solution;
-use pmath::sequences::CollatzSeq;
+use crate::{Error, Solution};
+use pmath::sequences::CollatzCache;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-problem!(Problem0014, 14, "Longest Collatz Sequence");
+problem!(Problem0014, 14, "Longest Collatz Sequence", "837799", 5);
 
 impl Solution for Problem0014 {
     fn solve(&self) -> String {
-        (1u64..1_000_000)
-            .max_by_key(|&n| CollatzSeq::new(n).count())
-            .unwrap()
-            .to_string()
+        longest_collatz(|| false).unwrap().to_string()
     }
+
+    fn solve_cancellable(&self, cancel: &AtomicBool) -> Result<String, Error> {
+        longest_collatz(|| cancel.load(Ordering::Relaxed))
+            .map(|start| start.to_string())
+            .ok_or(Error::Cancelled)
+    }
+}
+
+/// Find the starting number under one million with the longest Collatz sequence.
+///
+/// Returns [None] as soon as `cancelled` returns `true`,
+/// which is checked every 10 000 starting numbers.
+fn longest_collatz(cancelled: impl Fn() -> bool) -> Option<u64> {
+    const LIMIT: u64 = 1_000_000;
+
+    // sequences of the later starting numbers mostly continue with the already computed ones
+    let mut cache = CollatzCache::new(LIMIT as usize);
+    let mut longest = (0, 0);
+    for n in 1..LIMIT {
+        if n.is_multiple_of(10_000) && cancelled() {
+            return None;
+        }
+        let length = cache.length(n);
+        if length > longest.1 {
+            longest = (n, length);
+        }
+    }
+    Some(longest.0)
 }
@@ -2,7 +2,14 @@ use crate::Solution;
 use malachite::Natural;
 use malachite::base::num::arithmetic::traits::Factorial;
 
-problem!(Problem0015, 15, "Lattice Paths");
+problem!(
+    Problem0015,
+    15,
+    "Lattice Paths",
+    "137846528820",
+    5,
+    ["combinatorics"]
+);
 
 impl Solution for Problem0015 {
     fn solve(&self) -> String {
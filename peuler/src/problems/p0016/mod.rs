@@ -1,18 +1,32 @@
-use crate::Solution;
+use crate::{Solution, SolutionValue};
+use malachite::Integer;
 use malachite::Natural;
 use malachite::base::num::arithmetic::traits::PowerOf2;
 use malachite::base::num::conversion::traits::Digits;
+use std::fmt::Write;
 
-problem!(Problem0016, 16, "Power Digit Sum");
+problem!(Problem0016, 16, "Power Digit Sum", "1366", 5);
 
 impl Solution for Problem0016 {
     fn solve(&self) -> String {
-        Natural::power_of_2(1000)
-            .to_digits_desc(&10)
-            .into_iter()
-            .sum::<u64>()
-            .to_string()
+        self.solve_typed().to_string()
     }
+
+    fn solve_into(&self, buf: &mut String) {
+        buf.clear();
+        write!(buf, "{}", power_digit_sum()).expect("Cannot write to String.");
+    }
+
+    fn solve_typed(&self) -> SolutionValue {
+        SolutionValue::Integer(Integer::from(power_digit_sum()))
+    }
+}
+
+fn power_digit_sum() -> u64 {
+    Natural::power_of_2(1000)
+        .to_digits_desc(&10)
+        .into_iter()
+        .sum()
 }
 
 // this is a slower, pen and paper style solution
@@ -2,7 +2,7 @@ use crate::Solution;
 use std::collections::HashMap;
 use std::sync::LazyLock;
 
-problem!(Problem0017, 17, "Number Letter Counts");
+problem!(Problem0017, 17, "Number Letter Counts", "21124", 5);
 
 impl Solution for Problem0017 {
     fn solve(&self) -> String {
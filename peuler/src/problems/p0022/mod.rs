@@ -1,6 +1,6 @@
 use crate::Solution;
 
-problem!(Problem0022, 22, "Names Scores");
+problem!(Problem0022, 22, "Names Scores", "871198282", 5);
 
 impl Solution for Problem0022 {
     fn solve(&self) -> String {
@@ -1,40 +1,58 @@
-use crate::Solution;
-use pmath::factors::sum_of_proper_divisors_0_to_n;
-use std::collections::HashSet;
+use crate::{Error, Solution};
+use pmath::factors::{DivisorClass, sum_of_proper_divisors_0_to_n};
+use std::sync::atomic::{AtomicBool, Ordering};
 
-problem!(Problem0023, 23, "Non-Abundant Sums");
+problem!(Problem0023, 23, "Non-Abundant Sums", "4179871", 5);
 
 impl Solution for Problem0023 {
     fn solve(&self) -> String {
-        const UPPER_BOUND: u64 = 28123;
+        non_abundant_sum(|| false).unwrap().to_string()
+    }
 
-        let mut result = 0;
+    fn solve_cancellable(&self, cancel: &AtomicBool) -> Result<String, Error> {
+        non_abundant_sum(|| cancel.load(Ordering::Relaxed))
+            .map(|sum| sum.to_string())
+            .ok_or(Error::Cancelled)
+    }
+}
 
-        let mut abundant_numbers = Vec::new();
-        for (i, sum) in sum_of_proper_divisors_0_to_n(UPPER_BOUND)
-            .into_iter()
-            .enumerate()
-        {
-            if sum > i as u64 {
-                abundant_numbers.push(i as u64);
-            }
-        }
+/// Find the sum of all positive integers which cannot be written as the sum of two abundant numbers.
+///
+/// Returns [None] as soon as `cancelled` returns `true`,
+/// which is checked before pairing each abundant number with the larger ones.
+fn non_abundant_sum(cancelled: impl Fn() -> bool) -> Option<u64> {
+    // all integers greater than this can be written as the sum of two abundant numbers
+    const UPPER_BOUND: usize = 28123;
 
-        let abundant_numbers_set = abundant_numbers.iter().copied().collect::<HashSet<u64>>();
+    let abundant_numbers = sum_of_proper_divisors_0_to_n(UPPER_BOUND)
+        .into_iter()
+        .enumerate()
+        .skip(1)
+        .filter(|&(i, sum)| DivisorClass::from_sum(i, sum) == DivisorClass::Abundant)
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
 
-        'outer: for num in 1..(UPPER_BOUND + 1) {
-            let limit = num / 2;
-            for addend in &abundant_numbers {
-                if *addend > limit {
-                    break;
-                }
-                if abundant_numbers_set.contains(&(num - addend)) {
-                    continue 'outer;
-                }
+    // mark every sum of two abundant numbers which is within the bound
+    let mut is_abundant_sum = vec![false; UPPER_BOUND + 1];
+    for (i, &addend1) in abundant_numbers.iter().enumerate() {
+        if cancelled() {
+            return None;
+        }
+        for &addend2 in &abundant_numbers[i..] {
+            let sum = addend1 + addend2;
+            if sum > UPPER_BOUND {
+                break;
             }
-            result += num;
+            is_abundant_sum[sum] = true;
         }
-
-        result.to_string()
     }
+
+    Some(
+        is_abundant_sum
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, is_sum)| !is_sum)
+            .map(|(num, _)| num as u64)
+            .sum(),
+    )
 }
@@ -1,7 +1,14 @@
 use crate::Solution;
 use pmath::factorial;
 
-problem!(Problem0024, 24, "Lexicographic Permutations");
+problem!(
+    Problem0024,
+    24,
+    "Lexicographic Permutations",
+    "2783915460",
+    5,
+    ["combinatorics"]
+);
 
 impl Solution for Problem0024 {
     fn solve(&self) -> String {
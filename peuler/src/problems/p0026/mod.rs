@@ -2,7 +2,7 @@ use crate::Solution;
 use pmath::ord;
 use pmath::primes::coprime;
 
-problem!(Problem0026, 26, "Reciprocal Cycles");
+problem!(Problem0026, 26, "Reciprocal Cycles", "983", 5);
 
 impl Solution for Problem0026 {
     fn solve(&self) -> String {
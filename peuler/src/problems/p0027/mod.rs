@@ -1,7 +1,7 @@
 use crate::Solution;
 use pmath::primes::{is_prime, sieve_of_eratosthenes};
 
-problem!(Problem0027, 27, "Quadratic Primes");
+problem!(Problem0027, 27, "Quadratic Primes", "-59231", 5, ["primes"]);
 
 impl Solution for Problem0027 {
     fn solve(&self) -> String {
@@ -1,7 +1,7 @@
 use crate::Solution;
 use pmath::sequences::{OddNatNumSeq, OddNatNumSqSeq, Sequence};
 
-problem!(Problem0028, 28, "Number Spiral Diagonals");
+problem!(Problem0028, 28, "Number Spiral Diagonals", "669171001", 5);
 
 impl Solution for Problem0028 {
     fn solve(&self) -> String {
@@ -1,6 +1,6 @@
 use crate::Solution;
 
-problem!(Problem0029, 29, "Distinct Powers");
+problem!(Problem0029, 29, "Distinct Powers", "9183", 5);
 
 impl Solution for Problem0029 {
     fn solve(&self) -> String {
@@ -1,7 +1,7 @@
 use crate::Solution;
 use pmath::newtons_method;
 
-problem!(Problem0030, 30, "Digit Fifth Powers");
+problem!(Problem0030, 30, "Digit Fifth Powers", "443839", 5);
 
 impl Solution for Problem0030 {
     fn solve(&self) -> String {
@@ -1,7 +1,14 @@
 use crate::Solution;
 use std::collections::HashMap;
 
-problem!(Problem0031, 31, "Coin Sums");
+problem!(
+    Problem0031,
+    31,
+    "Coin Sums",
+    "73682",
+    5,
+    ["dynamic-programming"]
+);
 
 impl Solution for Problem0031 {
     fn solve(&self) -> String {
@@ -3,7 +3,14 @@ use itertools::Itertools;
 use pmath::digits::digits_to_int;
 use std::collections::HashSet;
 
-problem!(Problem0032, 32, "Pandigital Products");
+problem!(
+    Problem0032,
+    32,
+    "Pandigital Products",
+    "45228",
+    5,
+    ["pandigital"]
+);
 
 impl Solution for Problem0032 {
     fn solve(&self) -> String {
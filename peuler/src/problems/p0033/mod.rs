@@ -3,7 +3,7 @@ use itertools::Itertools;
 use pmath::digits::digits;
 use pmath::gcd;
 
-problem!(Problem0033, 33, "Digit Cancelling Fractions");
+problem!(Problem0033, 33, "Digit Cancelling Fractions", "100", 5);
 
 impl Solution for Problem0033 {
     fn solve(&self) -> String {
@@ -1,7 +1,7 @@
 use crate::Solution;
 use pmath::{factorial_0_to_n, newtons_method};
 
-problem!(Problem0034, 34, "Digit Factorials");
+problem!(Problem0034, 34, "Digit Factorials", "40730", 5);
 
 impl Solution for Problem0034 {
     fn solve(&self) -> String {
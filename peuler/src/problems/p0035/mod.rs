@@ -1,64 +1,53 @@
-use crate::Solution;
-use pmath::digits::digits;
-use pmath::primes::sieve_of_eratosthenes;
-use std::collections::HashMap;
+use crate::prime_cache::with_primes;
+use crate::{Error, Solution};
+use pmath::digits::digit_rotations;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-problem!(Problem0035, 35, "Circular Primes");
+problem!(Problem0035, 35, "Circular Primes", "55", 5, ["primes"]);
 
 impl Solution for Problem0035 {
     fn solve(&self) -> String {
-        // generate primes below 1,000,000 using sieve of eratosthenes
-        let mut primes = sieve_of_eratosthenes(1_000_000 - 1);
-
-        // remove all primes with digits 0, 2, 4, 5, 6, 8
-        // we do that because when we rotate a number with one of those digits
-        // when that digit gets to the end of the number, the number will be divisible by 2 or 5
-        // and therefore not prime
-        primes.retain(|&n| digits(n, 10).all(|digit| !matches!(digit, 0 | 2 | 4 | 5 | 6 | 8)));
+        circular_primes(|| false).unwrap().to_string()
+    }
 
-        // since we removed all primes with digits 0, 2, 4, 5, 6, 8, we also removed number 2 and 5,
-        // but these are circular primes, so we add them back (because their rotations are themselves)
-        primes.insert(0, 2);
-        primes.insert(2, 5);
+    fn solve_cancellable(&self, cancel: &AtomicBool) -> Result<String, Error> {
+        circular_primes(|| cancel.load(Ordering::Relaxed))
+            .map(|count| count.to_string())
+            .ok_or(Error::Cancelled)
+    }
+}
 
-        // create a hashmap of primes for a faster lookup and marking of the circular ones
-        let mut primes_map: HashMap<u64, bool> = HashMap::with_capacity(primes.len());
-        for prime in &primes {
-            primes_map.insert(*prime, false);
-        }
+/// Count the circular primes below one million.
+///
+/// Returns [None] as soon as `cancelled` returns `true`,
+/// which is checked before sieving and then every 10 000 primes.
+fn circular_primes(cancelled: impl Fn() -> bool) -> Option<usize> {
+    const LIMIT: u64 = 1_000_000;
 
-        // for each prime, check if it's circular and mark it, and all its rotations as circular
-        let mut rotations = Vec::new();
-        for mut n in primes {
-            let num_len = digits(n, 10).len();
-            let mut circular_prime = true;
+    if cancelled() {
+        return None;
+    }
 
-            rotations.clear();
-            rotations.push(n);
+    // primes below 1,000,000,
+    // rotations have at most as many digits as the prime, so they are also below the limit
+    with_primes(LIMIT - 1, |primes| {
+        // lookup table for a constant time primality check of the rotations
+        let mut is_prime = vec![false; LIMIT as usize];
+        for &prime in primes {
+            is_prime[prime as usize] = true;
+        }
 
-            for _ in 0..(num_len - 1) {
-                let last_digit = n % 10;
-                n /= 10;
-                n += last_digit * 10_u64.pow((num_len - 1) as u32);
-                rotations.push(n);
-                if !primes_map.contains_key(&n) {
-                    circular_prime = false;
-                    break;
-                }
+        // a prime is circular if all its rotations are prime,
+        // checking stops at the first composite rotation
+        let mut count = 0;
+        for (i, &prime) in primes.iter().enumerate() {
+            if i.is_multiple_of(10_000) && cancelled() {
+                return None;
             }
-
-            if circular_prime {
-                for rotation in &rotations {
-                    primes_map.insert(*rotation, true);
-                }
+            if digit_rotations(prime, 10).all(|rotation| is_prime[rotation as usize]) {
+                count += 1;
             }
         }
-
-        // count primes marked as circular
-        primes_map
-            .into_iter()
-            .filter(|(_, primality)| *primality)
-            .count()
-            .to_string()
-    }
+        Some(count)
+    })
 }
@@ -1,7 +1,14 @@
 use crate::Solution;
 use pmath::digits::is_palindrome;
 
-problem!(Problem0036, 36, "Double-base Palindromes");
+problem!(
+    Problem0036,
+    36,
+    "Double-base Palindromes",
+    "872187",
+    5,
+    ["palindromes"]
+);
 
 impl Solution for Problem0036 {
     fn solve(&self) -> String {
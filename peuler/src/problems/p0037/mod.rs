@@ -3,7 +3,14 @@ use itertools::Itertools;
 use pmath::digits::{digits, digits_to_int};
 use pmath::primes::is_prime;
 
-problem!(Problem0037, 37, "Truncatable Primes");
+problem!(
+    Problem0037,
+    37,
+    "Truncatable Primes",
+    "748317",
+    5,
+    ["primes"]
+);
 
 impl Solution for Problem0037 {
     fn solve(&self) -> String {
@@ -2,7 +2,14 @@ use crate::Solution;
 use itertools::Itertools;
 use pmath::digits::{digits, digits_to_int};
 
-problem!(Problem0038, 38, "Pandigital Multiples");
+problem!(
+    Problem0038,
+    38,
+    "Pandigital Multiples",
+    "932718654",
+    5,
+    ["pandigital"]
+);
 
 impl Solution for Problem0038 {
     fn solve(&self) -> String {
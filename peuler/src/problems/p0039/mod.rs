@@ -2,7 +2,14 @@ use crate::Solution;
 use itertools::Itertools;
 use pmath::gcd;
 
-problem!(Problem0039, 39, "Integer Right Triangles");
+problem!(
+    Problem0039,
+    39,
+    "Integer Right Triangles",
+    "840",
+    5,
+    ["pythagorean-triples"]
+);
 
 impl Solution for Problem0039 {
     fn solve(&self) -> String {
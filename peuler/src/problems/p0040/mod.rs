@@ -1,7 +1,7 @@
 use crate::Solution;
 use pmath::digits::digits;
 
-problem!(Problem0040, 40, "Champernowne's Constant");
+problem!(Problem0040, 40, "Champernowne's Constant", "210", 5);
 
 impl Solution for Problem0040 {
     fn solve(&self) -> String {
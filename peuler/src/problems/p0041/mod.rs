@@ -3,7 +3,14 @@ use itertools::Itertools;
 use pmath::digits::digits_to_int;
 use pmath::primes::is_prime;
 
-problem!(Problem0041, 41, "Pandigital Prime");
+problem!(
+    Problem0041,
+    41,
+    "Pandigital Prime",
+    "7652413",
+    5,
+    ["pandigital", "primes"]
+);
 
 impl Solution for Problem0041 {
     fn solve(&self) -> String {
@@ -1,6 +1,6 @@
 use crate::Solution;
 
-problem!(Problem0042, 42, "Coded Triangle Numbers");
+problem!(Problem0042, 42, "Coded Triangle Numbers", "162", 5);
 
 impl Solution for Problem0042 {
     fn solve(&self) -> String {
@@ -3,7 +3,14 @@ use pmath::digits::digits_to_int;
 use std::collections::HashMap;
 use std::sync::LazyLock;
 
-problem!(Problem0043, 43, "Sub-string Divisibility");
+problem!(
+    Problem0043,
+    43,
+    "Sub-string Divisibility",
+    "16695334890",
+    5,
+    ["pandigital"]
+);
 
 impl Solution for Problem0043 {
     fn solve(&self) -> String {
@@ -1,7 +1,7 @@
 use crate::Solution;
 use std::collections::HashSet;
 
-problem!(Problem0044, 44, "Pentagon Numbers");
+problem!(Problem0044, 44, "Pentagon Numbers", "5482660", 5);
 
 impl Solution for Problem0044 {
     fn solve(&self) -> String {
@@ -1,6 +1,12 @@
 use crate::Solution;
 
-problem!(Problem0045, 45, "Triangular, Pentagonal, and Hexagonal");
+problem!(
+    Problem0045,
+    45,
+    "Triangular, Pentagonal, and Hexagonal",
+    "1533776805",
+    5
+);
 
 impl Solution for Problem0045 {
     fn solve(&self) -> String {
@@ -1,7 +1,14 @@
 use crate::Solution;
 use pmath::primes::is_prime;
 
-problem!(Problem0046, 46, "Goldbach's Other Conjecture");
+problem!(
+    Problem0046,
+    46,
+    "Goldbach's Other Conjecture",
+    "5777",
+    5,
+    ["primes"]
+);
 
 impl Solution for Problem0046 {
     fn solve(&self) -> String {
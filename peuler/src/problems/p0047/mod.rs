@@ -1,7 +1,14 @@
 use crate::Solution;
 use pmath::factors::distinct_prime_factors;
 
-problem!(Problem0047, 47, "Distinct Primes Factors");
+problem!(
+    Problem0047,
+    47,
+    "Distinct Primes Factors",
+    "134043",
+    5,
+    ["primes"]
+);
 
 impl Solution for Problem0047 {
     fn solve(&self) -> String {
@@ -1,21 +1,36 @@
-use crate::Solution;
+use crate::{Solution, SolutionValue};
+use malachite::Integer;
+use std::fmt::Write;
 
-problem!(Problem0048, 48, "Self Powers");
+problem!(Problem0048, 48, "Self Powers", "9110846700", 5);
 
 impl Solution for Problem0048 {
     fn solve(&self) -> String {
-        // full manual calculation method commented out below, this is better
-        // by using mod at every step we discard unnecessary digits
+        self.solve_typed().to_string()
+    }
+
+    fn solve_into(&self, buf: &mut String) {
+        buf.clear();
+        write!(buf, "{}", self_powers()).expect("Cannot write to String.");
+    }
 
-        let mut result: u64 = 0;
+    fn solve_typed(&self) -> SolutionValue {
+        SolutionValue::Integer(Integer::from(self_powers()))
+    }
+}
 
-        for n in 1..1001 {
-            result += power_mod(n, n);
-            result %= MODULO;
-        }
+fn self_powers() -> u64 {
+    // full manual calculation method commented out below, this is better
+    // by using mod at every step we discard unnecessary digits
 
-        result.to_string()
+    let mut result: u64 = 0;
+
+    for n in 1..1001 {
+        result += power_mod(n, n);
+        result %= MODULO;
     }
+
+    result
 }
 
 const MODULO: u64 = 10_000_000_000;
@@ -1,95 +1,75 @@
-use crate::Solution;
-use pmath::primes::sieve_of_eratosthenes;
-use std::collections::HashSet;
-
-problem!(Problem0050, 50, "Consecutive Prime Sum");
+use crate::prime_cache::with_primes;
+use crate::{Error, Solution};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+problem!(
+    Problem0050,
+    50,
+    "Consecutive Prime Sum",
+    "997651",
+    5,
+    ["primes"]
+);
 
 impl Solution for Problem0050 {
     fn solve(&self) -> String {
-        const LIMIT: u64 = 1_000_000;
-
-        let primes = sieve_of_eratosthenes(LIMIT - 1); // generate all primes less than 1_000_000
-        let primes_set = primes.iter().copied().collect::<HashSet<_>>(); // store primes in a set for faster lookup
-        let biggest_prime = primes[primes.len() - 1]; // get the biggest prime from prime vector
-
-        // we will use a sliding window to find the longest consecutive prime sum
-        // we will start with the biggest window and shrink it by one and slide until we find a consecutive prime sum
-
-        let mut sum: u64 = 0; // sum of consecutive primes
-        let mut i: usize = 0; // the first index of a window
-        let mut j: usize = 0; // the first index after a window
-        // create an initial window (intentionally bigger than biggest_prime -> it is definitely not a consecutive prime sum)
-        while sum <= biggest_prime {
-            sum += primes[j];
-            j += 1;
-        }
-
-        // flag that indicates where our window is located
-        // true = window is on the left (from index 0 to something)
-        // false = window is on the right
-        let mut flag = true;
-
-        // each iteration of this loop, a window gets smaller by one
-        'outer: loop {
-            if flag {
-                // the window is on the left,
-                // we need to remove the last element (right)
-                // and then slide the window to the right
-
-                // remove last element
-                sum -= primes[j - 1];
-                j -= 1;
-
-                // slide window to the right
-                while sum < biggest_prime {
-                    if primes_set.contains(&sum) {
-                        break 'outer;
-                    }
-
-                    sum += primes[j];
-                    j += 1;
-                    sum -= primes[i];
-                    i += 1;
-                }
+        longest_prime_sum(|| false).unwrap().to_string()
+    }
 
-                // check if the last sum is a prime (not done in the loop above)
-                if primes_set.contains(&sum) {
-                    break 'outer;
-                }
+    fn solve_cancellable(&self, cancel: &AtomicBool) -> Result<String, Error> {
+        longest_prime_sum(|| cancel.load(Ordering::Relaxed))
+            .map(|sum| sum.to_string())
+            .ok_or(Error::Cancelled)
+    }
+}
 
-                // set flag to false, as the window is now on the right
-                flag = false;
-            } else {
-                // window is on the right
-                // we need to remove the first element (left)
-                // and then slide the window to the left
+/// Find the prime below one million that is the sum of the most consecutive primes.
+///
+/// Returns [None] as soon as `cancelled` returns `true`,
+/// which is checked before sieving and then for every length of the runs.
+fn longest_prime_sum(cancelled: impl Fn() -> bool) -> Option<u64> {
+    const LIMIT: u64 = 1_000_000;
 
-                // remove first element
-                sum -= primes[i];
-                i += 1;
+    if cancelled() {
+        return None;
+    }
 
-                // slide window to the left
-                while i > 0 {
-                    if primes_set.contains(&sum) {
-                        break 'outer;
-                    }
+    with_primes(LIMIT - 1, |primes| {
+        // lookup table for a constant time primality check of the sums
+        let mut is_prime = vec![false; LIMIT as usize];
+        for &prime in primes {
+            is_prime[prime as usize] = true;
+        }
 
-                    sum -= primes[j - 1];
-                    j -= 1;
-                    sum += primes[i - 1];
-                    i -= 1;
+        // prefix_sums[k] is the sum of the first k primes,
+        // so the sum of primes[i..j] is prefix_sums[j] - prefix_sums[i]
+        let prefix_sums = [0]
+            .into_iter()
+            .chain(primes.iter().scan(0, |sum, &prime| {
+                *sum += prime;
+                Some(*sum)
+            }))
+            .collect::<Vec<u64>>();
+
+        // the sum of a run is the smallest when it starts at the first prime,
+        // so only runs up to that length need to be considered, starting with the longest one
+        let max_length = prefix_sums.partition_point(|&sum| sum < LIMIT) - 1;
+        for length in (1..=max_length).rev() {
+            if cancelled() {
+                return None;
+            }
+            // slide the window to the right while the sum stays below the limit
+            for start in 0..=(primes.len() - length) {
+                let sum = prefix_sums[start + length] - prefix_sums[start];
+                if sum >= LIMIT {
+                    break;
                 }
-
-                // check if the last sum is a prime (not done in the loop above)
-                if primes_set.contains(&sum) {
-                    break 'outer;
+                if is_prime[sum as usize] {
+                    return Some(sum);
                 }
-
-                // set flag to true, as the window is now on the left
-                flag = true;
             }
         }
 
-        sum.to_string()
-    }
+        unreachable!("A single prime is always a consecutive prime sum.")
+    })
 }
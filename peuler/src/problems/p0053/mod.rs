@@ -3,7 +3,13 @@ use malachite::Natural;
 use malachite::base::num::basic::traits::One;
 use std::sync::LazyLock;
 
-problem!(Problem0053, 53, "Combinatoric Selections");
+problem!(
+    Problem0053,
+    53,
+    "Combinatoric Selections",
+    "4075",
+    ["combinatorics"]
+);
 
 impl Solution for Problem0053 {
     fn solve(&self) -> String {
@@ -2,7 +2,7 @@ use crate::Solution;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
-problem!(Problem0054, 54, "Poker Hands");
+problem!(Problem0054, 54, "Poker Hands", "376");
 
 impl Solution for Problem0054 {
     fn solve(&self) -> String {
@@ -1,7 +1,7 @@
 use crate::Solution;
 use pmath::digits::{is_palindrome, reverse};
 
-problem!(Problem0055, 55, "Lychrel Numbers");
+problem!(Problem0055, 55, "Lychrel Numbers", "249", ["palindromes"]);
 
 impl Solution for Problem0055 {
     fn solve(&self) -> String {
@@ -3,7 +3,7 @@ use malachite::Natural;
 use malachite::base::num::arithmetic::traits::Pow;
 use malachite::base::num::conversion::traits::Digits;
 
-problem!(Problem0056, 56, "Powerful Digit Sum");
+problem!(Problem0056, 56, "Powerful Digit Sum", "972");
 
 impl Solution for Problem0056 {
     fn solve(&self) -> String {
@@ -4,7 +4,13 @@ use malachite::base::num::arithmetic::traits::FloorLogBase;
 use malachite::base::num::basic::traits::{One, Two};
 use malachite::rational::Rational;
 
-problem!(Problem0057, 57, "Square Root Convergents");
+problem!(
+    Problem0057,
+    57,
+    "Square Root Convergents",
+    "153",
+    ["continued-fractions"]
+);
 
 impl Solution for Problem0057 {
     fn solve(&self) -> String {
@@ -1,47 +1,34 @@
 use crate::Solution;
+use itertools::Itertools;
 use pmath::primes::is_prime;
+use pmath::sequences::spiral_diagonals;
 
-problem!(Problem0058, 58, "Spiral Primes");
+problem!(Problem0058, 58, "Spiral Primes", "26241", ["primes"]);
 
 impl Solution for Problem0058 {
     fn solve(&self) -> String {
-        let mut primes: u32 = 3;
-        let mut nums: u32 = 5;
-        let mut size: u32 = 2;
-
-        let mut top_left: [u64; 2] = [5, 4];
-        let mut top_right: [u64; 2] = [3, 2];
-        let mut bottom_left: [u64; 2] = [7, 6];
-        let mut bottom_right: [u64; 2] = [9, 8];
-
-        while (primes as f64) / (nums as f64) >= 0.1 {
-            size += 1;
-            nums += 4;
-
-            top_left[1] += 8;
-            top_right[1] += 8;
-            bottom_left[1] += 8;
-            bottom_right[1] += 8;
-
-            top_left[0] += top_left[1];
-            top_right[0] += top_right[1];
-            bottom_left[0] += bottom_left[1];
-            bottom_right[0] += bottom_right[1];
-
-            if is_prime(top_left[0]).0 {
-                primes += 1;
-            }
-            if is_prime(top_right[0]).0 {
-                primes += 1;
-            }
-            if is_prime(bottom_left[0]).0 {
-                primes += 1;
-            }
-            if is_prime(bottom_right[0]).0 {
-                primes += 1;
+        // large enough spiral, the ratio of primes falls below 10% long before its last layer
+        const MAX_SIZE: u64 = 99_999;
+
+        let mut primes = 0;
+        // every layer adds 4 corners to the diagonals (the center 1 is not a prime)
+        for (layer, corners) in spiral_diagonals(MAX_SIZE)
+            .skip(1)
+            .tuples::<(_, _, _, _)>()
+            .enumerate()
+        {
+            primes += [corners.0, corners.1, corners.2, corners.3]
+                .into_iter()
+                .filter(|&n| is_prime(n).0)
+                .count();
+
+            let side = 2 * layer + 3;
+            let diagonal_count = 2 * side - 1;
+            if 10 * primes < diagonal_count {
+                return side.to_string();
             }
         }
 
-        (2 * size - 1).to_string()
+        unreachable!("The ratio of primes falls below 10% within the spiral.")
     }
 }
@@ -1,7 +1,7 @@
 use crate::Solution;
 use std::str;
 
-problem!(Problem0059, 59, "XOR Decryption");
+problem!(Problem0059, 59, "XOR Decryption", "129448");
 
 impl Solution for Problem0059 {
     fn solve(&self) -> String {
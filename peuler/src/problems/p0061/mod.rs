@@ -1,7 +1,7 @@
 use crate::Solution;
 use std::collections::HashMap;
 
-problem!(Problem0061, 61, "Cyclical Figurate Numbers");
+problem!(Problem0061, 61, "Cyclical Figurate Numbers", "28684");
 
 impl Solution for Problem0061 {
     fn solve(&self) -> String {
@@ -1,7 +1,7 @@
 use crate::Solution;
 use pmath::newtons_method;
 
-problem!(Problem0063, 63, "Powerful Digit Counts");
+problem!(Problem0063, 63, "Powerful Digit Counts", "49");
 
 impl Solution for Problem0063 {
     fn solve(&self) -> String {
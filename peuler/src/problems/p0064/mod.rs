@@ -1,7 +1,13 @@
 use crate::Solution;
 use pmath::SimpleContinuedFraction;
 
-problem!(Problem0064, 64, "Odd Period Square Roots");
+problem!(
+    Problem0064,
+    64,
+    "Odd Period Square Roots",
+    "1322",
+    ["continued-fractions"]
+);
 
 impl Solution for Problem0064 {
     fn solve(&self) -> String {
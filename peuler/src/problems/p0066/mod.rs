@@ -1,36 +1,43 @@
-use crate::Solution;
-use malachite::Natural;
-use malachite::base::num::basic::traits::{One, Zero};
-use pmath::SimpleContinuedFraction;
+use crate::{Error, Solution};
+use pmath::pell_fundamental;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-problem!(Problem0066, 66, "Diophantine Equation");
+problem!(
+    Problem0066,
+    66,
+    "Diophantine Equation",
+    "661",
+    ["continued-fractions"]
+);
 
 impl Solution for Problem0066 {
     fn solve(&self) -> String {
-        const MAX: u16 = 1000;
+        largest_minimal_solution(|| false).unwrap().to_string()
+    }
+
+    fn solve_cancellable(&self, cancel: &AtomicBool) -> Result<String, Error> {
+        largest_minimal_solution(|| cancel.load(Ordering::Relaxed))
+            .map(|d| d.to_string())
+            .ok_or(Error::Cancelled)
+    }
+}
 
-        // this is the Pell's equation
-        // the solution to the Pell's equation minimizing x is one of the convergents of the continued fraction of sqrt(d)
-        // so we just need to try out convergents of sqrt(d) in increasing order until we find one that satisfies the equation
+/// Find the `D` up to 1000 for which the minimal solution of `x^2 - D y^2 = 1` has the largest `x`.
+///
+/// Returns [None] as soon as `cancelled` returns `true`,
+/// which is checked before solving the equation for each `D`.
+fn largest_minimal_solution(cancelled: impl Fn() -> bool) -> Option<u64> {
+    const MAX: u64 = 1000;
 
-        let mut max_x = Natural::ZERO;
-        let mut max_d = 0;
-        for d in 1..MAX {
-            let sqrt_continued_fraction = SimpleContinuedFraction::from_sqrt(d as i64);
-            for convergent in sqrt_continued_fraction.convergents() {
-                if convergent.denominator_ref() * convergent.denominator_ref() * Natural::from(d)
-                    + Natural::ONE
-                    == convergent.numerator_ref() * convergent.numerator_ref()
-                {
-                    if convergent.numerator_ref() > &max_x {
-                        max_x = convergent.numerator_ref().clone();
-                        max_d = d;
-                    }
-                    break;
-                }
-            }
+    // this is the Pell's equation, perfect squares have no solutions in positive integers
+    let mut largest = None;
+    for d in 1..=MAX {
+        if cancelled() {
+            return None;
+        }
+        if let Some((x, _)) = pell_fundamental(d) {
+            largest = largest.max(Some((x, d)));
         }
-
-        max_d.to_string()
     }
+    largest.map(|(_, d)| d)
 }
@@ -1,7 +1,7 @@
 use crate::Solution;
 use pmath::phi_0_to_n;
 
-problem!(Problem0069, 69, "Totient Maximum");
+problem!(Problem0069, 69, "Totient Maximum", "510510", ["totient"]);
 
 impl Solution for Problem0069 {
     fn solve(&self) -> String {
@@ -2,7 +2,13 @@ use crate::Solution;
 use pmath::digits::is_permutation;
 use pmath::phi_0_to_n;
 
-problem!(Problem0070, 70, "Totient Permutation");
+problem!(
+    Problem0070,
+    70,
+    "Totient Permutation",
+    "8319823",
+    ["totient"]
+);
 
 impl Solution for Problem0070 {
     fn solve(&self) -> String {
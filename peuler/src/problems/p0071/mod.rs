@@ -1,6 +1,6 @@
 use crate::Solution;
 
-problem!(Problem0071, 71, "Ordered Fractions");
+problem!(Problem0071, 71, "Ordered Fractions", "428570");
 
 impl Solution for Problem0071 {
     fn solve(&self) -> String {
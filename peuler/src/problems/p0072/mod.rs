@@ -1,7 +1,13 @@
 use crate::Solution;
 use pmath::phi_0_to_n;
 
-problem!(Problem0072, 72, "Counting Fractions");
+problem!(
+    Problem0072,
+    72,
+    "Counting Fractions",
+    "303963552391",
+    ["totient"]
+);
 
 impl Solution for Problem0072 {
     fn solve(&self) -> String {
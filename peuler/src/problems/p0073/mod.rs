@@ -1,6 +1,6 @@
 use crate::Solution;
 
-problem!(Problem0073, 73, "Counting Fractions in a Range");
+problem!(Problem0073, 73, "Counting Fractions in a Range", "7295372");
 
 impl Solution for Problem0073 {
     fn solve(&self) -> String {
@@ -2,7 +2,7 @@ use crate::Solution;
 use pmath::digits::digits;
 use pmath::factorial_0_to_n;
 
-problem!(Problem0074, 74, "Digit Factorial Chains");
+problem!(Problem0074, 74, "Digit Factorial Chains", "402");
 
 impl Solution for Problem0074 {
     fn solve(&self) -> String {
@@ -1,7 +1,13 @@
 use crate::Solution;
 use pmath::gcd;
 
-problem!(Problem0075, 75, "Singular Integer Right Triangles");
+problem!(
+    Problem0075,
+    75,
+    "Singular Integer Right Triangles",
+    "161667",
+    ["pythagorean-triples"]
+);
 
 impl Solution for Problem0075 {
     fn solve(&self) -> String {
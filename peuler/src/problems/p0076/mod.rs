@@ -1,7 +1,13 @@
 use crate::Solution;
 use pmath::partition_p;
 
-problem!(Problem0076, 76, "Counting Summations");
+problem!(
+    Problem0076,
+    76,
+    "Counting Summations",
+    "190569291",
+    ["dynamic-programming"]
+);
 
 impl Solution for Problem0076 {
     fn solve(&self) -> String {
@@ -1,7 +1,13 @@
 use crate::Solution;
 use pmath::partition_prime_0_to_n;
 
-problem!(Problem0077, 77, "Prime Summations");
+problem!(
+    Problem0077,
+    77,
+    "Prime Summations",
+    "71",
+    ["dynamic-programming", "primes"]
+);
 
 impl Solution for Problem0077 {
     fn solve(&self) -> String {
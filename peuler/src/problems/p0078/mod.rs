@@ -1,6 +1,12 @@
 use crate::Solution;
 
-problem!(Problem0078, 78, "Coin Partitions");
+problem!(
+    Problem0078,
+    78,
+    "Coin Partitions",
+    "55374",
+    ["dynamic-programming"]
+);
 
 impl Solution for Problem0078 {
     fn solve(&self) -> String {
@@ -4,7 +4,7 @@ use malachite::base::num::arithmetic::traits::Pow;
 use malachite::rational::Rational;
 use pmath::SimpleContinuedFraction;
 
-problem!(Problem0080, 80, "Square Root Digital Expansion");
+problem!(Problem0080, 80, "Square Root Digital Expansion", "40886");
 
 impl Solution for Problem0080 {
     fn solve(&self) -> String {
@@ -1,47 +1,31 @@
 use crate::Solution;
-use std::cmp::min;
+use pmath::grid::min_path_two_ways;
 
-problem!(Problem0081, 81, "Path Sum: Two Ways");
+problem!(
+    Problem0081,
+    81,
+    "Path Sum: Two Ways",
+    "427337",
+    ["dynamic-programming"]
+);
 
 impl Solution for Problem0081 {
     fn solve(&self) -> String {
         const INPUT: &str = include_str!("p081_matrix.txt");
-        let mut matrix = INPUT
-            .trim()
-            .lines()
-            .map(|line| {
-                line.trim()
-                    .split(',')
-                    .map(|num_str| num_str.parse::<u32>().unwrap())
-                    .collect::<Vec<u32>>()
-            })
-            .collect::<Vec<Vec<u32>>>();
-
-        // we start from the bottom right corner and work our way up
-        // to each position we add the smaller between the right and bottom values
-
-        // first let's handle special cases
-
-        // last/bottom row
-        let last_row = matrix.len() - 1;
-        for i in (0..(matrix[last_row].len() - 1)).rev() {
-            matrix[last_row][i] += matrix[last_row][i + 1];
-        }
-
-        // last/right column
-        let last_col = matrix[0].len() - 1;
-        for i in (0..(matrix.len() - 1)).rev() {
-            matrix[i][last_col] += matrix[i + 1][last_col];
-        }
-
-        // finally, we do this for general cases
-        for i in (0..(matrix.len() - 1)).rev() {
-            for j in (0..(matrix[0].len() - 1)).rev() {
-                matrix[i][j] += min(matrix[i][j + 1], matrix[i + 1][j]);
-            }
-        }
-
-        // the first cell now contains the minimal path sum
-        matrix[0][0].to_string()
+        min_path_two_ways(&parse_matrix(INPUT)).to_string()
     }
 }
+
+/// Parse the rows of comma-separated numbers of a matrix.
+pub(crate) fn parse_matrix(input: &str) -> Vec<Vec<u64>> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            line.trim()
+                .split(',')
+                .map(|num_str| num_str.parse().unwrap())
+                .collect()
+        })
+        .collect()
+}
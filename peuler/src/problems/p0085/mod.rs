@@ -1,6 +1,12 @@
 use crate::Solution;
 
-problem!(Problem0085, 85, "Counting Rectangles");
+problem!(
+    Problem0085,
+    85,
+    "Counting Rectangles",
+    "2772",
+    ["combinatorics"]
+);
 
 impl Solution for Problem0085 {
     fn solve(&self) -> String {
@@ -1,6 +1,6 @@
 use crate::Solution;
 
-problem!(Problem0089, 89, "Roman Numerals");
+problem!(Problem0089, 89, "Roman Numerals", "743");
 
 impl Solution for Problem0089 {
     fn solve(&self) -> String {
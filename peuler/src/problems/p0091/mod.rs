@@ -2,7 +2,12 @@ use crate::Solution;
 use pmath::gcd;
 use std::cmp::min;
 
-problem!(Problem0091, 91, "Right Triangles with Integer Coordinates");
+problem!(
+    Problem0091,
+    91,
+    "Right Triangles with Integer Coordinates",
+    "14234"
+);
 
 impl Solution for Problem0091 {
     fn solve(&self) -> String {
@@ -1,7 +1,7 @@
 use crate::Solution;
 use pmath::digits::digits;
 
-problem!(Problem0092, 92, "Square Digit Chains");
+problem!(Problem0092, 92, "Square Digit Chains", "8581146");
 
 impl Solution for Problem0092 {
     fn solve(&self) -> String {
@@ -42,7 +42,7 @@ fn process_number(num: u64, cache: &mut Vec<Option<bool>>) -> bool {
         1 => false,
         89 => true,
         _ => {
-            let next = digits(num, 10).map(|x| (x as u64).pow(2)).sum();
+            let next = digits(num, 10).map(|x| x.pow(2)).sum();
 
             if next as usize >= cache.len() {
                 process_number(next, cache)
@@ -1,7 +1,7 @@
 use crate::Solution;
 use pmath::factors::sum_of_proper_divisors_0_to_n;
 
-problem!(Problem0095, 95, "Amicable Chains");
+problem!(Problem0095, 95, "Amicable Chains", "14316");
 
 impl Solution for Problem0095 {
     fn solve(&self) -> String {
@@ -1,6 +1,6 @@
 use crate::Solution;
 
-problem!(Problem0097, 97, "Large Non-Mersenne Prime");
+problem!(Problem0097, 97, "Large Non-Mersenne Prime", "8739992577");
 
 impl Solution for Problem0097 {
     fn solve(&self) -> String {
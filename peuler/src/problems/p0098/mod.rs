@@ -2,7 +2,7 @@ use crate::Solution;
 use itertools::Itertools;
 use pmath::digits::{digits, is_permutation};
 
-problem!(Problem0098, 98, "Anagramic Squares");
+problem!(Problem0098, 98, "Anagramic Squares", "18769");
 
 impl Solution for Problem0098 {
     fn solve(&self) -> String {
@@ -1,6 +1,6 @@
 use crate::Solution;
 
-problem!(Problem0099, 99, "Largest Exponential");
+problem!(Problem0099, 99, "Largest Exponential", "709");
 
 impl Solution for Problem0099 {
     fn solve(&self) -> String {
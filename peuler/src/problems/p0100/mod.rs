@@ -1,7 +1,7 @@
 use crate::Solution;
 use pmath::isqrt;
 
-problem!(Problem0100, 100, "Arranged Probability");
+problem!(Problem0100, 100, "Arranged Probability", "756872327473");
 
 impl Solution for Problem0100 {
     fn solve(&self) -> String {
@@ -2,7 +2,7 @@ use crate::Solution;
 use pmath::linalg::{Point, Vector};
 use std::f64::consts::PI;
 
-problem!(Problem0102, 102, "Triangle Containment");
+problem!(Problem0102, 102, "Triangle Containment", "228");
 
 impl Solution for Problem0102 {
     fn solve(&self) -> String {
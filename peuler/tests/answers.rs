@@ -0,0 +1,30 @@
+use peuler::{PEuler, ProjectEuler};
+
+#[test]
+fn all_answers() {
+    //! Test that every available problem has a known answer and its solver produces it.
+    //!
+    //! All problems are checked before failing, so that every broken solver is reported.
+
+    let mut failures = Vec::new();
+    for problem in PEuler::new().problems() {
+        let id = problem.id();
+        match problem.answer() {
+            Some(answer) => {
+                let solution = problem.solve();
+                if solution != answer {
+                    failures.push(format!(
+                        "Problem {id:04}: expected {answer}, got {solution}"
+                    ));
+                }
+            }
+            None => failures.push(format!("Problem {id:04}: the answer is not known")),
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{} problem(s) do not match their answers:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,329 @@
+#![cfg(feature = "cli")]
+
+use peuler::{PEuler, ProjectEuler};
+use std::fs;
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+/// Run the CLI binary with the given arguments.
+fn peuler(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_peuler"))
+        .args(args)
+        .output()
+        .expect("Failed to run the CLI.")
+}
+
+#[test]
+fn output_list() {
+    //! Test that `--output` writes the `--list` output to a file.
+
+    let path = std::env::temp_dir().join(format!("peuler-list-{}.txt", std::process::id()));
+    let path_str = path.to_str().unwrap();
+
+    let redirected = peuler(&["--list", "--output", path_str]);
+    assert!(redirected.status.success());
+    assert!(redirected.stdout.is_empty());
+
+    let contents = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    let printed = peuler(&["--list"]);
+    assert!(printed.status.success());
+    assert_eq!(contents, String::from_utf8(printed.stdout).unwrap());
+    assert!(contents.starts_with("Problem 0001: Multiples of 3 or 5"));
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn color_never() {
+    //! Test that `--color never` produces no ANSI escapes.
+
+    for args in [
+        &["--list", "--color", "never"][..],
+        &["1-3", "--color", "never"],
+        &["1", "--benchmark", "3", "--color", "never"],
+    ] {
+        let output = peuler(args);
+        assert!(output.status.success());
+        assert!(!output.stdout.is_empty());
+        assert!(!output.stdout.contains(&0x1b), "{args:?}");
+    }
+
+    let output = peuler(&["1", "--color", "always"]);
+    assert!(output.stdout.contains(&0x1b));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_matches_sequential() {
+    //! Test that `--parallel` prints the same rows as the sequential run.
+
+    let sequential = peuler(&["1-30"]);
+    let parallel = peuler(&["1-30", "--parallel"]);
+    assert!(sequential.status.success());
+    assert!(parallel.status.success());
+    assert_eq!(sequential.stdout, parallel.stdout);
+    assert_eq!(
+        String::from_utf8(parallel.stdout).unwrap().lines().count(),
+        30
+    );
+}
+
+#[test]
+fn quiet() {
+    //! Test that `--quiet` prints only the results.
+
+    let output = peuler(&["1-3", "--quiet"]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "233168\n4613732\n6857\n"
+    );
+
+    let output = peuler(&["1-2", "--benchmark", "3", "--quiet"]);
+    assert!(output.status.success());
+    let output = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(output.lines().count(), 2);
+    assert!(!output.contains("Problem"));
+    assert!(output.starts_with("233168"));
+}
+
+#[cfg(feature = "progress")]
+#[test]
+fn progress_smoke() {
+    //! Test that runs over multiple problems complete with the progress bar enabled.
+
+    let output = peuler(&["1-5"]);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap().lines().count(), 5);
+
+    let output = peuler(&["1-3", "--benchmark", "3"]);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap().lines().count(), 3);
+}
+
+#[test]
+fn verify() {
+    //! Test that `--verify` passes for all implemented problems.
+
+    let output = peuler(&["--verify"]);
+    assert!(output.status.success());
+    let output = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(output.lines().count(), PEuler::new().count());
+    for line in output.lines() {
+        assert!(line.ends_with("PASS"), "{line}");
+    }
+}
+
+#[test]
+fn repl() {
+    //! Test `--repl` with scripted input.
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_peuler"))
+        .arg("--repl")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to run the CLI.");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"1\n\nlist\n1\n9999\nfoo\n2\nquit\n3\n")
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.matches("233168 (").count(), 2);
+    assert!(stdout.contains("4613732 ("));
+    assert!(stdout.contains("Problem 0001: Multiples of 3 or 5"));
+    assert!(!stdout.contains("6857"));
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(stderr.lines().count(), 2);
+}
+
+#[test]
+fn ids_from_stdin() {
+    //! Test reading the problem identifiers from stdin with `-`.
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_peuler"))
+        .args(["-", "--quiet"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to run the CLI.");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"3\n1-2\n\n2\n")
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "233168\n4613732\n6857\n"
+    );
+}
+
+#[test]
+fn ids_from_file() {
+    //! Test reading the problem identifiers from a file with `--from-file`.
+
+    let path = std::env::temp_dir().join(format!("peuler-ids-{}.txt", std::process::id()));
+    fs::write(&path, "1\n2,3\n").unwrap();
+    let output = peuler(&["--from-file", path.to_str().unwrap(), "--quiet"]);
+    fs::remove_file(&path).unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "233168\n4613732\n6857\n"
+    );
+}
+
+/// A JSON benchmark export of problems with the given identifiers and mean times.
+#[cfg(feature = "serde")]
+fn benchmark_export(means: &[(usize, u64)]) -> String {
+    let entries = means
+        .iter()
+        .map(|(id, mean_ns)| {
+            format!(
+                r#"{{"id": {id}, "answer": "0", "iterations": 3, "mean_ns": {mean_ns}, "stddev_ns": 0, "min_ns": {mean_ns}, "max_ns": {mean_ns}, "median_ns": {mean_ns}}}"#
+            )
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", entries.join(", "))
+}
+
+/// Run the `compare` subcommand on two exports with the given mean times and extra arguments.
+#[cfg(feature = "serde")]
+fn compare_exports(
+    name: &str,
+    baseline: &[(usize, u64)],
+    current: &[(usize, u64)],
+    args: &[&str],
+) -> Output {
+    let dir = std::env::temp_dir();
+    let id = std::process::id();
+    let baseline_path = dir.join(format!("peuler-{name}-baseline-{id}.json"));
+    let current_path = dir.join(format!("peuler-{name}-current-{id}.json"));
+    fs::write(&baseline_path, benchmark_export(baseline)).unwrap();
+    fs::write(&current_path, benchmark_export(current)).unwrap();
+
+    let mut compare_args = vec![
+        "compare",
+        baseline_path.to_str().unwrap(),
+        current_path.to_str().unwrap(),
+    ];
+    compare_args.extend_from_slice(args);
+    let output = peuler(&compare_args);
+    fs::remove_file(&baseline_path).unwrap();
+    fs::remove_file(&current_path).unwrap();
+    output
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn compare() {
+    //! Test the `compare` subcommand on two small exports with a known delta.
+
+    let output = compare_exports(
+        "delta",
+        &[(1, 1000), (2, 2000), (3, 10)],
+        &[(1, 500), (2, 3000), (4, 10)],
+        &[],
+    );
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Problem 0001: 1000.000 ns -> 500.000 ns (-50.00%)\n\
+         Problem 0002: 2.000 µs -> 3.000 µs (+50.00%)\n\
+         Problem 0003: only in the baseline\n\
+         Problem 0004: only in the current run\n\
+         Total: 3.000 µs -> 3.500 µs (+16.67%)\n"
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn compare_zero_baseline() {
+    //! Test that the `compare` subcommand prints no percentage for a zero baseline.
+
+    let output = compare_exports("zero", &[(1, 0), (2, 10)], &[(1, 100), (3, 10)], &[]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Problem 0001: 0.000 ns -> 100.000 ns (n/a)\n\
+         Problem 0002: only in the baseline\n\
+         Problem 0003: only in the current run\n\
+         Total: 0.000 ns -> 100.000 ns (n/a)\n"
+    );
+
+    let output = compare_exports("disjoint", &[(1, 10)], &[(2, 10)], &[]);
+    assert!(output.status.success());
+    assert!(
+        String::from_utf8(output.stdout)
+            .unwrap()
+            .ends_with("Total: 0.000 ns -> 0.000 ns (n/a)\n")
+    );
+}
+
+#[cfg(all(feature = "color", feature = "serde"))]
+#[test]
+fn compare_color() {
+    //! Test that the `compare` subcommand accepts `--color`.
+
+    let never = compare_exports("never", &[(1, 10)], &[(1, 20)], &["--color", "never"]);
+    assert!(never.status.success());
+    assert!(!never.stdout.contains(&0x1b));
+
+    let always = compare_exports("always", &[(1, 10)], &[(1, 20)], &["--color", "always"]);
+    assert!(always.status.success());
+    assert!(always.stdout.contains(&0x1b));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn benchmark_json() {
+    //! Test that `--benchmark` with `--json` writes an export which `compare` reads.
+
+    let path = std::env::temp_dir().join(format!("peuler-bench-{}.json", std::process::id()));
+    let path_str = path.to_str().unwrap();
+
+    let output = peuler(&["--benchmark", "3", "--json", path_str, "1-2", "--quiet"]);
+    assert!(output.status.success());
+    let export = fs::read_to_string(&path).unwrap();
+    assert!(export.contains(r#""mean_ns""#));
+    assert!(export.contains(r#""answer": "233168""#));
+
+    let output = peuler(&["compare", path_str, path_str]);
+    assert!(output.status.success());
+    let comparison = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(comparison.lines().count(), 3);
+    assert!(comparison.lines().all(|line| line.ends_with("(+0.00%)")));
+
+    // the results of single solves do not have the benchmark statistics
+    let solve = std::env::temp_dir().join(format!("peuler-solve-{}.json", std::process::id()));
+    fs::write(&solve, r#"[{"id": 1, "duration_ns": 1000}]"#).unwrap();
+    let output = peuler(&["compare", solve.to_str().unwrap(), path_str]);
+    fs::remove_file(&solve).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert!(!output.status.success());
+}
+
+#[test]
+fn completions() {
+    //! Test that generating bash completions succeeds.
+
+    let output = peuler(&["completions", "bash"]);
+    assert!(output.status.success());
+    let script = String::from_utf8(output.stdout).unwrap();
+    assert!(!script.is_empty());
+    assert!(script.contains("peuler"));
+
+    assert!(!peuler(&["completions", "nosuchshell"]).status.success());
+}
@@ -0,0 +1,17 @@
+#![cfg(feature = "rayon")]
+
+use peuler::{PEuler, ProjectEuler};
+
+#[test]
+fn solve_all_parallel() {
+    //! Test that solving in parallel gives the same results as solving sequentially.
+
+    let project_euler = PEuler::new();
+    let sequential = project_euler
+        .problems()
+        .map(|problem| (problem.id(), problem.solve()))
+        .collect::<Vec<_>>();
+    let parallel = project_euler.solve_all_parallel();
+
+    assert_eq!(parallel, sequential);
+}
@@ -0,0 +1,66 @@
+use peuler::ParametrizedSolution;
+use peuler::problems::{
+    Problem0001, Problem0003, Problem0004, Problem0005, Problem0006, Problem0010,
+};
+
+#[test]
+fn problem_0001() {
+    //! Test [Problem0001] with custom upper limits against a brute force sum.
+
+    let problem = Problem0001::new();
+    assert_eq!(problem.solve_with(10), "23");
+    for limit in [0, 1, 3, 4, 15, 16, 100, 1234] {
+        let expected = (1..limit).filter(|i| i % 3 == 0 || i % 5 == 0).sum::<u64>();
+        assert_eq!(problem.solve_with(limit), expected.to_string());
+    }
+}
+
+#[test]
+fn problem_0003() {
+    //! Test [Problem0003] with the example from the problem and larger semiprimes.
+
+    let problem = Problem0003::new();
+    assert_eq!(problem.solve_with(13195), "29");
+    assert_eq!(problem.solve_with(2), "2");
+    assert_eq!(problem.solve_with(1024), "2");
+    assert_eq!(problem.solve_with(999_983 * 1_000_003), "1000003");
+    assert_eq!(problem.solve_with(1_000_003 * 1_000_000_007), "1000000007");
+    // both factors are too large for trial division
+    assert_eq!(
+        problem.solve_with(999_999_937 * 1_000_000_007),
+        "1000000007"
+    );
+    assert_eq!(problem.solve_with(18_446_743_979_220_271_189), "4294967291");
+}
+
+#[test]
+fn problem_0004() {
+    //! Test [Problem0004] with the example from the problem and other numbers of digits.
+
+    let problem = Problem0004::new();
+    assert_eq!(problem.solve_with(2), "9009");
+    assert_eq!(problem.solve_with(1), "9");
+    assert_eq!(problem.solve_with(4), "99000099");
+}
+
+#[test]
+fn problem_0005() {
+    //! Test [Problem0005] with the example from the problem.
+
+    assert_eq!(Problem0005::new().solve_with(10), "2520");
+}
+
+#[test]
+fn problem_0006() {
+    //! Test [Problem0006] with the example from the problem.
+
+    assert_eq!(Problem0006::new().solve_with(10), "2640");
+}
+
+#[test]
+fn problem_0010() {
+    //! Test [Problem0010] with the example from the problem.
+
+    assert_eq!(Problem0010::new().solve_with(10), "17");
+    assert_eq!(Problem0010::new().solve_with(2), "0");
+}
@@ -0,0 +1,426 @@
+use malachite::Integer;
+use peuler::{Error, PEuler, Problem, ProjectEuler, Solution, SolutionValue};
+use std::collections::{BTreeSet, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A [ProjectEuler] that only provides [ProjectEuler::problems],
+/// so that the default implementations of the other methods are used.
+struct DefaultProjectEuler(PEuler);
+impl ProjectEuler for DefaultProjectEuler {
+    fn problems<'a>(&'a self) -> Box<dyn Iterator<Item = &'a dyn Problem> + 'a> {
+        self.0.problems()
+    }
+}
+
+fn ids<'a>(problems: impl IntoIterator<Item = &'a dyn Problem>) -> Vec<usize> {
+    problems.into_iter().map(|problem| problem.id()).collect()
+}
+
+#[test]
+fn problems_in_range_inclusive() {
+    //! Test [ProjectEuler::problems_in_range] with inclusive ranges.
+
+    let project_euler = PEuler::new();
+    let default = DefaultProjectEuler(PEuler::new());
+
+    let expected = vec![48, 49, 50, 52];
+    assert_eq!(ids(project_euler.problems_in_range(48..=52)), expected);
+    assert_eq!(ids(default.problems_in_range(48..=52)), expected);
+
+    assert_eq!(ids(project_euler.problems_in_range(1..=1)), vec![1]);
+    assert_eq!(ids(default.problems_in_range(1..=1)), vec![1]);
+}
+
+#[test]
+fn problems_in_range_exclusive() {
+    //! Test [ProjectEuler::problems_in_range] with exclusive ranges.
+
+    let project_euler = PEuler::new();
+    let default = DefaultProjectEuler(PEuler::new());
+
+    let expected = vec![48, 49, 50];
+    assert_eq!(ids(project_euler.problems_in_range(48..52)), expected);
+    assert_eq!(ids(default.problems_in_range(48..52)), expected);
+
+    // 51 is not available, so the range is empty
+    assert_eq!(
+        ids(project_euler.problems_in_range(51..52)),
+        Vec::<usize>::new()
+    );
+    assert_eq!(ids(default.problems_in_range(51..52)), Vec::<usize>::new());
+    assert_eq!(
+        ids(project_euler.problems_in_range(5..5)),
+        Vec::<usize>::new()
+    );
+    assert_eq!(ids(default.problems_in_range(5..5)), Vec::<usize>::new());
+}
+
+#[test]
+fn problems_in_range_unbounded() {
+    //! Test [ProjectEuler::problems_in_range] with unbounded ranges.
+
+    let project_euler = PEuler::new();
+    let default = DefaultProjectEuler(PEuler::new());
+    let all = ids(project_euler.problems());
+
+    assert_eq!(ids(project_euler.problems_in_range(..)), all);
+    assert_eq!(ids(default.problems_in_range(..)), all);
+
+    assert_eq!(ids(project_euler.problems_in_range(..4)), vec![1, 2, 3]);
+    assert_eq!(ids(default.problems_in_range(..4)), vec![1, 2, 3]);
+
+    let tail = all
+        .iter()
+        .copied()
+        .filter(|&id| id >= 90)
+        .collect::<Vec<_>>();
+    assert!(!tail.is_empty());
+    assert_eq!(ids(project_euler.problems_in_range(90..)), tail);
+    assert_eq!(ids(default.problems_in_range(90..)), tail);
+}
+
+#[test]
+fn find_by_title() {
+    //! Test [ProjectEuler::find_by_title].
+
+    let project_euler = PEuler::new();
+
+    assert_eq!(
+        ids(project_euler.find_by_title("prime")),
+        vec![3, 7, 10, 27, 35, 37, 41, 47, 49, 50, 58, 77, 97]
+    );
+    assert_eq!(ids(project_euler.find_by_title("PALINDROME")), vec![4, 36]);
+    assert!(project_euler.find_by_title("no such problem").is_empty());
+}
+
+#[test]
+fn difficulty() {
+    //! Test that the early problems report their difficulty.
+
+    let project_euler = PEuler::new();
+    for problem in project_euler.problems_in_range(1..=50) {
+        assert_eq!(problem.difficulty(), Some(5), "Problem {}", problem.id());
+    }
+}
+
+#[test]
+fn problems_with_tag() {
+    //! Test [ProjectEuler::problems_with_tag].
+
+    let project_euler = PEuler::new();
+
+    assert_eq!(
+        ids(project_euler.problems_with_tag("palindromes")),
+        vec![4, 36, 55]
+    );
+    assert_eq!(
+        ids(project_euler.problems_with_tag("dynamic-programming")),
+        vec![18, 31, 67, 76, 77, 78, 81, 82]
+    );
+    assert!(project_euler.problems_with_tag("no-such-tag").is_empty());
+}
+
+#[test]
+fn solved_ids() {
+    //! Test [ProjectEuler::solved_ids].
+
+    let project_euler = PEuler::new();
+    let solved = project_euler.solved_ids();
+
+    assert_eq!(
+        solved,
+        project_euler
+            .problems()
+            .map(|problem| problem.id())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(solved.len(), project_euler.count());
+    assert!(solved.is_sorted());
+    assert_eq!(PEuler::with_ids(&[3, 1, 51]).solved_ids(), vec![1, 3]);
+}
+
+#[test]
+fn missing_ids() {
+    //! Test [ProjectEuler::missing_ids].
+
+    let project_euler = PEuler::new();
+
+    let missing = project_euler.missing_ids(100);
+    assert!(missing.contains(&51));
+    assert!(missing.contains(&60));
+    assert!(!missing.contains(&1));
+    assert!(!missing.contains(&100));
+    assert_eq!(
+        missing.len() + project_euler.problems_in_range(1..=100).count(),
+        100
+    );
+
+    assert_eq!(project_euler.missing_ids(50), Vec::<usize>::new());
+    assert_eq!(project_euler.missing_ids(52), vec![51]);
+    assert_eq!(project_euler.missing_ids(0), Vec::<usize>::new());
+    assert_eq!(
+        project_euler.missing_ids(105),
+        [
+            51, 60, 68, 79, 83, 84, 86, 87, 88, 90, 93, 94, 96, 101, 103, 104, 105
+        ]
+    );
+}
+
+#[test]
+fn benchmark_n() {
+    //! Test [Solution::benchmark_n].
+
+    let project_euler = PEuler::new();
+    let (solution, sample) = project_euler.problem(1).unwrap().benchmark_n(5);
+
+    assert_eq!(solution, "233168");
+    assert_eq!(sample.len(), 5);
+    assert!(sample.mean().is_some());
+}
+
+#[test]
+fn benchmark_stats() {
+    //! Test that the fields of [Solution::benchmark_stats] are consistent.
+
+    let project_euler = PEuler::new();
+    let result = project_euler.problem(1).unwrap().benchmark_stats(7);
+
+    assert_eq!(result.answer, "233168");
+    assert_eq!(result.iterations, 7);
+    assert!(result.min_ns as f64 <= result.median_ns);
+    assert!(result.median_ns <= result.max_ns as f64);
+    assert!(result.min_ns as f64 <= result.mean_ns);
+    assert!(result.mean_ns <= result.max_ns as f64);
+    assert!(result.stddev_ns >= 0.0);
+
+    let result = project_euler.problem(1).unwrap().benchmark_stats(1);
+    assert_eq!(result.iterations, 1);
+    assert_eq!(result.min_ns, result.max_ns);
+    assert_eq!(result.stddev_ns, 0.0);
+}
+
+#[test]
+fn benchmark_warmup() {
+    //! Test [Solution::benchmark_warmup].
+
+    let project_euler = PEuler::new();
+    let problem = project_euler.problem(6).unwrap();
+
+    let (solution, sample) = problem.benchmark_warmup(3, 4);
+    assert_eq!(solution, "25164150");
+    assert_eq!(sample.len(), 4);
+
+    let (_, sample) = problem.benchmark_warmup(0, 1);
+    assert_eq!(sample.len(), 1);
+}
+
+#[test]
+fn url() {
+    //! Test [Problem::url].
+
+    let project_euler = PEuler::new();
+
+    let url = project_euler.problem(1).unwrap().url();
+    assert!(url.ends_with("problem=1"));
+    assert_eq!(url, "https://projecteuler.net/problem=1");
+    assert_eq!(
+        project_euler.problem(102).unwrap().url(),
+        "https://projecteuler.net/problem=102"
+    );
+}
+
+#[test]
+fn with_ids() {
+    //! Test [PEuler::with_ids].
+
+    assert_eq!(PEuler::with_ids(&[1, 2, 3]).problems().count(), 3);
+
+    // unavailable problems and duplicates are skipped, problems are sorted
+    let project_euler = PEuler::with_ids(&[50, 1, 51, 50, 102]);
+    assert_eq!(ids(project_euler.problems()), vec![1, 50, 102]);
+    assert_eq!(project_euler.solve(50).unwrap(), "997651");
+    assert!(project_euler.solve(2).is_err());
+
+    assert_eq!(PEuler::with_ids(&[]).problems().count(), 0);
+    assert_eq!(
+        ids(PEuler::with_ids(&(1..=200).collect::<Vec<_>>()).problems()),
+        ids(PEuler::new().problems())
+    );
+}
+
+#[test]
+fn problem_ordering() {
+    //! Test that problems are ordered and hashed by their identifiers.
+
+    let project_euler = PEuler::new();
+    let problems = [10, 3, 50, 1, 3, 25].map(|id| project_euler.problem(id).unwrap());
+
+    let sorted = problems.iter().copied().collect::<BTreeSet<_>>();
+    assert_eq!(ids(sorted), vec![1, 3, 10, 25, 50]);
+
+    let unique = problems.iter().copied().collect::<HashSet<_>>();
+    assert_eq!(unique.len(), 5);
+
+    assert!(problems[0] > problems[1]);
+    assert!(problems[1] == problems[4]);
+}
+
+struct CustomProblem {
+    id: usize,
+}
+impl Problem for CustomProblem {
+    fn id(&self) -> usize {
+        self.id
+    }
+
+    fn title(&self) -> &str {
+        "Custom Problem"
+    }
+}
+impl Solution for CustomProblem {
+    fn solve(&self) -> String {
+        (self.id * 2).to_string()
+    }
+}
+
+#[test]
+fn register() {
+    //! Test [PEuler::register].
+
+    let mut project_euler = PEuler::with_ids(&[1, 2, 3]);
+    assert_eq!(project_euler.solve(51), Err(Error::UnavailableProblem));
+
+    assert_eq!(
+        project_euler.register(Box::new(CustomProblem { id: 51 })),
+        Ok(())
+    );
+    assert_eq!(
+        project_euler.register(Box::new(CustomProblem { id: 0 })),
+        Ok(())
+    );
+    assert_eq!(ids(project_euler.problems()), vec![0, 1, 2, 3, 51]);
+    assert_eq!(project_euler.solve(51), Ok(String::from("102")));
+    assert_eq!(project_euler.problem(51).unwrap().title(), "Custom Problem");
+
+    // duplicates are rejected and the original problem is kept
+    assert_eq!(
+        project_euler.register(Box::new(CustomProblem { id: 1 })),
+        Err(Error::DuplicateProblem)
+    );
+    assert_eq!(project_euler.solve(1), Ok(String::from("233168")));
+    assert_eq!(project_euler.problems().count(), 5);
+    assert_eq!(project_euler.count(), 5);
+}
+
+#[test]
+fn count() {
+    //! Test [ProjectEuler::count].
+
+    let project_euler = PEuler::new();
+    let default = DefaultProjectEuler(PEuler::new());
+
+    assert_eq!(project_euler.count(), project_euler.problems().count());
+    assert_eq!(default.count(), project_euler.problems().count());
+    assert_eq!(PEuler::with_ids(&[1, 2, 3, 51]).count(), 3);
+}
+
+#[test]
+fn solve_typed() {
+    //! Test [Solution::solve_typed].
+
+    let project_euler = PEuler::new();
+
+    let solution = project_euler.problem(1).unwrap().solve_typed();
+    assert_eq!(solution, SolutionValue::Integer(Integer::from(233168)));
+    assert_eq!(solution.to_string(), "233168");
+
+    for problem in project_euler.problems_in_range(1..=20) {
+        assert_eq!(problem.solve_typed().to_string(), problem.solve());
+    }
+
+    // problems which compute an integer return it without formatting it first
+    for (id, answer) in [(3, 6857), (16, 1366), (20, 648), (48, 9_110_846_700u64)] {
+        assert_eq!(
+            project_euler.problem(id).unwrap().solve_typed(),
+            SolutionValue::Integer(Integer::from(answer))
+        );
+    }
+
+    let solution = CustomProblem { id: 7 }.solve_typed();
+    assert_eq!(solution, SolutionValue::Integer(Integer::from(14)));
+}
+
+struct TextProblem;
+impl Solution for TextProblem {
+    fn solve(&self) -> String {
+        String::from("0123")
+    }
+}
+
+#[test]
+fn solve_typed_text() {
+    //! Test that [Solution::solve_typed] keeps non-canonical integers as text.
+
+    let solution = TextProblem.solve_typed();
+    assert_eq!(solution, SolutionValue::Text(String::from("0123")));
+    assert_eq!(solution.to_string(), "0123");
+}
+
+#[test]
+fn solve_many() {
+    //! Test [ProjectEuler::solve_many].
+
+    let project_euler = PEuler::new();
+
+    assert_eq!(
+        project_euler.solve_many(&[2, 51, 1, 0, 2]),
+        vec![
+            Ok(String::from("4613732")),
+            Err(Error::UnavailableProblem),
+            Ok(String::from("233168")),
+            Err(Error::UnavailableProblem),
+            Ok(String::from("4613732")),
+        ]
+    );
+    assert!(project_euler.solve_many(&[]).is_empty());
+}
+
+#[test]
+fn solve_into() {
+    //! Test that [Solution::solve_into] yields the same string as [Solution::solve].
+
+    let project_euler = PEuler::new();
+
+    let mut buf = String::from("stale contents");
+    for id in [1, 2, 3, 16, 20, 48] {
+        let problem = project_euler.problem(id).unwrap();
+        problem.solve_into(&mut buf);
+        assert_eq!(buf, problem.solve());
+    }
+
+    TextProblem.solve_into(&mut buf);
+    assert_eq!(buf, "0123");
+}
+
+#[test]
+fn solve_cancellable() {
+    //! Test [ProjectEuler::solve_cancellable] with the flag set and unset.
+
+    let project_euler = PEuler::new();
+
+    let cancel = AtomicBool::new(true);
+    for id in [1, 14, 23, 35, 50, 62, 66] {
+        assert_eq!(
+            project_euler.solve_cancellable(id, &cancel),
+            Err(Error::Cancelled)
+        );
+    }
+
+    cancel.store(false, Ordering::Relaxed);
+    for id in [1, 14, 23, 35, 50, 62, 66] {
+        assert_eq!(
+            project_euler.solve_cancellable(id, &cancel),
+            project_euler.solve(id)
+        );
+    }
+}
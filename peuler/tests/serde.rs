@@ -0,0 +1,23 @@
+#![cfg(feature = "serde")]
+
+use peuler::{PEuler, ProblemResult, ProjectEuler};
+
+#[test]
+fn solve_all_json() {
+    //! Test that the JSON produced by [ProjectEuler::solve_all_json] deserializes back.
+
+    let project_euler = PEuler::new();
+    let results = serde_json::from_str::<Vec<ProblemResult>>(&project_euler.solve_all_json())
+        .expect("Cannot deserialize the results.");
+
+    assert_eq!(results.len(), project_euler.problems().count());
+
+    assert_eq!(results[0].id, 1);
+    assert_eq!(results[0].title, "Multiples of 3 or 5");
+    assert_eq!(results[0].answer, "233168");
+
+    let result = results.iter().find(|result| result.id == 10).unwrap();
+    assert_eq!(result.title, "Summation of Primes");
+    assert_eq!(result.answer, "142913828922");
+    assert!(result.duration_ns > 0);
+}
@@ -0,0 +1,25 @@
+use peuler::{Error, PEuler, ProjectEuler};
+
+#[test]
+fn verify_known() {
+    //! Test that problems with a correct solver verify against their answers.
+    //!
+    //! The answers of all problems are checked in the `answers` test.
+
+    let project_euler = PEuler::new();
+    for id in 1..=10 {
+        assert_eq!(project_euler.verify(id), Ok(true), "Problem {id:04}");
+    }
+}
+
+#[test]
+fn verify_unavailable() {
+    //! Test that verifying an unavailable problem returns an error.
+
+    let project_euler = PEuler::new();
+    assert_eq!(project_euler.verify(0), Err(Error::UnavailableProblem));
+    assert_eq!(
+        project_euler.verify(usize::MAX),
+        Err(Error::UnavailableProblem)
+    );
+}
@@ -0,0 +1,207 @@
+//! Calendar calculations in the proleptic Gregorian calendar.
+
+/// A day of the week.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    /// The first day of the week.
+    Monday,
+    /// The second day of the week.
+    Tuesday,
+    /// The third day of the week.
+    Wednesday,
+    /// The fourth day of the week.
+    Thursday,
+    /// The fifth day of the week.
+    Friday,
+    /// The sixth day of the week.
+    Saturday,
+    /// The seventh and last day of the week.
+    Sunday,
+}
+
+/// Check whether a year is a leap year.
+///
+/// Leap years are divisible by `4`, except for the ones divisible by `100` but not by `400`.
+/// # Arguments
+/// * `year` - The year to check.
+/// # Returns
+/// * Whether the year is a leap year.
+/// # Example
+/// ```
+/// use pmath::date::is_leap_year;
+///
+/// assert!(is_leap_year(2024));
+/// assert!(!is_leap_year(1900));
+/// assert!(is_leap_year(2000));
+/// ```
+pub fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// The number of days in a month.
+/// # Arguments
+/// * `year` - The year of the month.
+/// * `month` - The month, from `1` (January) to `12` (December).
+/// # Returns
+/// * The number of days in the month.
+/// # Panics
+/// * If `month` is not in the range `1..=12`.
+/// # Example
+/// ```
+/// use pmath::date::days_in_month;
+///
+/// assert_eq!(days_in_month(2023, 2), 28);
+/// assert_eq!(days_in_month(2024, 2), 29);
+/// assert_eq!(days_in_month(2024, 12), 31);
+/// ```
+pub fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => panic!("Month must be in the range 1..=12."),
+    }
+}
+
+/// The number of days from `1970-01-01` to a date.
+///
+/// Dates before `1970-01-01` give negative numbers.
+/// # Arguments
+/// * `year` - The year of the date.
+/// * `month` - The month of the date, from `1` (January) to `12` (December).
+/// * `day` - The day of the month, starting from `1`.
+/// # Returns
+/// * The number of days since `1970-01-01`.
+/// # Panics
+/// * If `month` is not in the range `1..=12`.
+/// * If `day` is not a day of the month.
+/// # Example
+/// ```
+/// use pmath::date::days_since_epoch;
+///
+/// assert_eq!(days_since_epoch(1970, 1, 1), 0);
+/// assert_eq!(days_since_epoch(1970, 2, 1), 31);
+/// assert_eq!(days_since_epoch(1969, 12, 31), -1);
+/// ```
+pub fn days_since_epoch(year: i64, month: u32, day: u32) -> i64 {
+    if !(1..=days_in_month(year, month)).contains(&day) {
+        panic!("Day must be a day of the month.");
+    }
+
+    // years are counted from March, so that the leap day is the last day of a year
+    let year = if month <= 2 { year - 1 } else { year };
+    // the calendar repeats every 400 years, which have 146097 days
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_from_march = i64::from((month + 9) % 12);
+    let day_of_year = (153 * month_from_march + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    // 719468 days pass from 0000-03-01 to 1970-01-01
+    era * 146097 + day_of_era - 719468
+}
+
+/// The day of the week of a date.
+/// # Arguments
+/// * `year` - The year of the date.
+/// * `month` - The month of the date, from `1` (January) to `12` (December).
+/// * `day` - The day of the month, starting from `1`.
+/// # Returns
+/// * The day of the week.
+/// # Panics
+/// * If `month` is not in the range `1..=12`.
+/// * If `day` is not a day of the month.
+/// # Example
+/// ```
+/// use pmath::date::{Weekday, day_of_week};
+///
+/// assert_eq!(day_of_week(1900, 1, 1), Weekday::Monday);
+/// assert_eq!(day_of_week(2000, 2, 29), Weekday::Tuesday);
+/// ```
+pub fn day_of_week(year: i64, month: u32, day: u32) -> Weekday {
+    const WEEKDAYS: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    // 1970-01-01 was a Thursday
+    WEEKDAYS[(days_since_epoch(year, month, day) + 3).rem_euclid(7) as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_leap_year_general() {
+        //! Test the [is_leap_year] on regular, century and negative years.
+
+        assert!(is_leap_year(1996));
+        assert!(!is_leap_year(1997));
+        assert!(!is_leap_year(2100));
+        assert!(is_leap_year(1600));
+        assert!(is_leap_year(0));
+        assert!(is_leap_year(-4));
+        assert!(!is_leap_year(-100));
+    }
+
+    #[test]
+    #[should_panic]
+    fn days_in_month_invalid_month() {
+        //! Test that the [days_in_month] panics when given an invalid month.
+
+        days_in_month(2000, 13);
+    }
+
+    #[test]
+    fn days_since_epoch_general() {
+        //! Test the [days_since_epoch] on dates around the epoch and far from it.
+
+        assert_eq!(days_since_epoch(1970, 1, 1), 0);
+        assert_eq!(days_since_epoch(1971, 1, 1), 365);
+        assert_eq!(days_since_epoch(1973, 1, 1), 365 + 365 + 366);
+        assert_eq!(days_since_epoch(1969, 1, 1), -365);
+        assert_eq!(days_since_epoch(2000, 3, 1), 11017);
+        assert_eq!(days_since_epoch(1900, 1, 1), -25567);
+        assert_eq!(days_since_epoch(0, 3, 1), -719468);
+
+        // consecutive days across a year and a leap day
+        assert_eq!(
+            days_since_epoch(2001, 1, 1) - days_since_epoch(2000, 12, 31),
+            1
+        );
+        assert_eq!(
+            days_since_epoch(2024, 3, 1) - days_since_epoch(2024, 2, 29),
+            1
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn days_since_epoch_invalid_day() {
+        //! Test that the [days_since_epoch] panics when given a day that is not in the month.
+
+        days_since_epoch(1900, 2, 29);
+    }
+
+    #[test]
+    fn day_of_week_known_dates() {
+        //! Test the [day_of_week] on dates with known weekdays.
+
+        assert_eq!(day_of_week(1900, 1, 1), Weekday::Monday);
+        assert_eq!(day_of_week(1901, 1, 1), Weekday::Tuesday);
+        assert_eq!(day_of_week(1969, 7, 20), Weekday::Sunday);
+        assert_eq!(day_of_week(1969, 12, 31), Weekday::Wednesday);
+        assert_eq!(day_of_week(1970, 1, 1), Weekday::Thursday);
+        assert_eq!(day_of_week(2000, 1, 1), Weekday::Saturday);
+        assert_eq!(day_of_week(2000, 2, 29), Weekday::Tuesday);
+        assert_eq!(day_of_week(2001, 9, 11), Weekday::Tuesday);
+        assert_eq!(day_of_week(2024, 1, 1), Weekday::Monday);
+        assert_eq!(day_of_week(1582, 10, 15), Weekday::Friday);
+    }
+}
@@ -321,6 +321,163 @@ where
     digits_to_int(digits(n, radix).rev(), radix)
 }
 
+/// Create an iterator over the rotations of the digits of an integer in the given radix.
+///
+/// The first rotation is the integer itself, and each next one moves
+/// the least significant digit to the most significant position.
+/// Rotations keep the number of digits of `n`, so they can have leading zeros.
+/// # Arguments
+/// * `n` - The integer to rotate.
+/// * `radix` - The radix to use for rotating the integer.
+/// # Returns
+/// * An iterator over all rotations of the integer, one for each of its digits.
+/// # Panics
+/// * If `n` is negative.
+/// * If `radix` is less than 2.
+/// * If `radix` does not fit in the type `T`.
+/// * If a rotation does not fit in the type `T`.
+/// # Example
+/// ```
+/// use pmath::digits::digit_rotations;
+///
+/// // 197 -> 719 -> 971
+/// assert_eq!(digit_rotations(197, 10).collect::<Vec<_>>(), vec![197, 719, 971]);
+/// // 120 -> 012 -> 201
+/// assert_eq!(digit_rotations(120, 10).collect::<Vec<_>>(), vec![120, 12, 201]);
+/// ```
+pub fn digit_rotations<T, U>(n: T, radix: U) -> impl Iterator<Item = T>
+where
+    T: PrimInt + ConstZero + ConstOne,
+    U: PrimInt + ConstOne,
+{
+    let length = digits(n, radix).len();
+    let radix = T::from(radix).unwrap();
+    let front_weight = radix.pow(length as u32 - 1);
+    let mut rotation = n;
+    (0..length).map(move |_| {
+        let current = rotation;
+        rotation = rotation % radix * front_weight + rotation / radix;
+        current
+    })
+}
+
+/// All distinct integers whose digits are a permutation of the digits of an integer.
+///
+/// Permutations keep the number of digits of `n`, so the ones starting with zeros
+/// are smaller integers with fewer digits.
+/// # Arguments
+/// * `n` - The integer to permute the digits of.
+/// * `radix` - The radix to use for the digits.
+/// # Returns
+/// * The distinct permutations in ascending order, including `n` itself.
+/// # Panics
+/// * If `n` is negative.
+/// * If `radix` is less than 2.
+/// * If `radix` does not fit in the type `T`.
+/// # Example
+/// ```
+/// use pmath::digits::digit_permutations;
+///
+/// assert_eq!(digit_permutations(123, 10), vec![123, 132, 213, 231, 312, 321]);
+/// // repeated digits do not give repeated permutations
+/// assert_eq!(digit_permutations(101, 10), vec![11, 101, 110]);
+/// ```
+pub fn digit_permutations<T, U>(n: T, radix: U) -> Vec<T>
+where
+    T: PrimInt + ConstZero + ConstOne,
+    U: PrimInt + ConstOne,
+{
+    // digits from the most significant one, in the smallest arrangement
+    let mut digits = digits(n, radix).collect::<Vec<_>>();
+    digits.sort_unstable();
+    let radix = T::from(radix).unwrap();
+
+    // lexicographic order of the digits is the ascending order of the integers
+    let mut permutations = Vec::new();
+    loop {
+        permutations.push(
+            digits
+                .iter()
+                .fold(T::ZERO, |value, &digit| value * radix + digit),
+        );
+
+        // advance to the next permutation, or stop if this was the largest one
+        let Some(pivot) = (1..digits.len()).rev().find(|&i| digits[i - 1] < digits[i]) else {
+            break;
+        };
+        let successor = (pivot..digits.len())
+            .rev()
+            .find(|&i| digits[i] > digits[pivot - 1])
+            .unwrap();
+        digits.swap(pivot - 1, successor);
+        digits[pivot..].reverse();
+    }
+    permutations
+}
+
+/// The number of occurrences of each decimal digit of an integer.
+///
+/// Integers have the same counts exactly when their digits are permutations of each other,
+/// so the counts can be used as a key for grouping such integers.
+/// # Arguments
+/// * `n` - The integer to count the digits of.
+/// # Returns
+/// * The counts, where the value at index `i` is the number of occurrences of the digit `i`.
+/// # Example
+/// ```
+/// use pmath::digits::digit_counts;
+///
+/// assert_eq!(digit_counts(1_002_003), [3, 1, 2, 1, 0, 0, 0, 0, 0, 0]);
+/// ```
+pub fn digit_counts(n: u64) -> [u8; 10] {
+    let mut counts = [0; 10];
+    for digit in digits(n, 10) {
+        counts[digit as usize] += 1;
+    }
+    counts
+}
+
+/// Check whether two integers have the same decimal digits.
+/// # Arguments
+/// * `a` - The first integer.
+/// * `b` - The second integer.
+/// # Returns
+/// * Whether the digits of `a` are a permutation of the digits of `b`.
+/// # Example
+/// ```
+/// use pmath::digits::same_digits;
+///
+/// assert!(same_digits(125_874, 251_748));
+/// assert!(!same_digits(123, 1230));
+/// ```
+pub fn same_digits(a: u64, b: u64) -> bool {
+    digit_counts(a) == digit_counts(b)
+}
+
+/// Group integers by their decimal digits.
+///
+/// Integers whose digits are permutations of each other end up in the same group.
+/// # Arguments
+/// * `nums` - The integers to group.
+/// # Returns
+/// * The groups, keyed by the [digit_counts] of their integers.
+///   Integers in a group keep the order in which they were given.
+/// # Example
+/// ```
+/// use pmath::digits::{digit_counts, group_by_digits};
+///
+/// let groups = group_by_digits([41_063_625, 56_623_104, 66_430_125, 1_000]);
+/// assert_eq!(groups.len(), 2);
+/// assert_eq!(groups[&digit_counts(41_063_625)], vec![41_063_625, 56_623_104, 66_430_125]);
+/// ```
+pub fn group_by_digits<I: IntoIterator<Item = u64>>(nums: I) -> HashMap<[u8; 10], Vec<u64>> {
+    let mut groups = HashMap::<_, Vec<_>>::new();
+    for n in nums {
+        groups.entry(digit_counts(n)).or_default().push(n);
+    }
+    groups
+}
+
 /// Lowercase character representations of hexadecimal digits.
 pub const HEX_DIGITS_LOWER: [char; 16] = [
     '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f',
@@ -935,4 +1092,119 @@ mod tests {
         assert_eq!(reverse(0b01011, 2), 0b1101);
         assert_eq!(reverse(0xabc, 16), 0xcba);
     }
+
+    // digit_rotations function tests
+
+    #[test]
+    #[should_panic]
+    fn digit_rotations_negative_integer() {
+        //! Test that the [digit_rotations] panics when given a negative integer.
+
+        digit_rotations(-123, 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn digit_rotations_invalid_radix() {
+        //! Test that the [digit_rotations] panics when given an invalid radix.
+
+        digit_rotations(123, 1);
+    }
+
+    #[test]
+    fn digit_rotations_general() {
+        //! Test that the [digit_rotations] correctly rotates integers.
+
+        assert_eq!(
+            digit_rotations(1193, 10).collect::<Vec<_>>(),
+            vec![1193, 3119, 9311, 1931]
+        );
+        assert_eq!(digit_rotations(0, 10).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(digit_rotations(7, 10).collect::<Vec<_>>(), vec![7]);
+        assert_eq!(
+            digit_rotations(100, 10).collect::<Vec<_>>(),
+            vec![100, 10, 1]
+        );
+        assert_eq!(
+            digit_rotations(0b1101u8, 2).collect::<Vec<_>>(),
+            vec![0b1101, 0b1110, 0b0111, 0b1011]
+        );
+        assert_eq!(
+            digit_rotations(0xabc, 16).collect::<Vec<_>>(),
+            vec![0xabc, 0xcab, 0xbca]
+        );
+    }
+
+    // digit_permutations function tests
+
+    #[test]
+    #[should_panic]
+    fn digit_permutations_negative_integer() {
+        //! Test that the [digit_permutations] panics when given a negative integer.
+
+        digit_permutations(-123, 10);
+    }
+
+    #[test]
+    fn digit_permutations_general() {
+        //! Test that the [digit_permutations] yields all distinct permutations in ascending order.
+
+        assert_eq!(digit_permutations(0, 10), vec![0]);
+        assert_eq!(digit_permutations(7, 10), vec![7]);
+        assert_eq!(digit_permutations(1111, 10), vec![1111]);
+        assert_eq!(digit_permutations(21, 10), vec![12, 21]);
+        assert_eq!(
+            digit_permutations(1487, 10).len(),
+            24,
+            "all digits are distinct"
+        );
+        assert_eq!(
+            digit_permutations(1122, 10),
+            vec![1122, 1212, 1221, 2112, 2121, 2211]
+        );
+        assert_eq!(digit_permutations(0b110u8, 2), vec![0b011, 0b101, 0b110]);
+        assert_eq!(digit_permutations(0x1f, 16), vec![0x1f, 0xf1]);
+    }
+
+    #[test]
+    fn digit_counts_general() {
+        //! Test the [digit_counts] on zero and integers with repeated digits.
+
+        assert_eq!(digit_counts(0), [1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(digit_counts(9_999), [0, 0, 0, 0, 0, 0, 0, 0, 0, 4]);
+        assert_eq!(digit_counts(u64::MAX).iter().sum::<u8>(), 20);
+    }
+
+    #[test]
+    fn same_digits_general() {
+        //! Test the [same_digits] on permuted multiples and integers with different digits.
+
+        assert!(same_digits(125_874, 251_748));
+        assert!(
+            (1..=6)
+                .map(|k| 142_857 * k)
+                .all(|multiple| same_digits(142_857, multiple))
+        );
+        assert!(same_digits(0, 0));
+        assert!(!same_digits(12, 120));
+        assert!(!same_digits(112, 122));
+    }
+
+    #[test]
+    fn group_by_digits_general() {
+        //! Test the [group_by_digits] on permutations and the cubic permutations.
+
+        let groups = group_by_digits([125_874, 100, 251_748, 10, 1]);
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[&digit_counts(125_874)], vec![125_874, 251_748]);
+        assert_eq!(groups[&digit_counts(1)], vec![1]);
+
+        // 41063625 = 345^3, 56623104 = 384^3, 66430125 = 405^3
+        let groups = group_by_digits((1..500u64).map(|n| n.pow(3)));
+        assert_eq!(
+            groups[&digit_counts(41_063_625)],
+            vec![41_063_625, 56_623_104, 66_430_125]
+        );
+        assert!(group_by_digits([]).is_empty());
+    }
 }
@@ -1,7 +1,9 @@
 //! Factors and divisors of integers.
 
-use crate::primes::sieve_of_eratosthenes;
+use crate::gcd;
+use crate::primes::{is_prime_mr, sieve_of_eratosthenes};
 use num_traits::{ConstOne, ConstZero, PrimInt};
+use std::cmp::Ordering;
 use std::iter::Sum;
 use std::iter::from_fn;
 use std::vec::IntoIter;
@@ -192,6 +194,346 @@ pub fn distinct_prime_factors<T: PrimInt + ConstZero + ConstOne>(n: T) -> Distin
     DistinctPrimeFactors::new(n)
 }
 
+/// The prime factorization of an integer.
+///
+/// Small factors are found by trial division, and the remaining cofactor is split
+/// with Pollard's rho algorithm (Brent's variant), using [is_prime_mr] to recognize primes.
+/// Unlike [prime_factors], this is fast even for products of two large primes.
+/// # Arguments
+/// * `n` - The integer to factorize.
+/// # Returns
+/// * The distinct prime factors of the integer and their multiplicities in ascending order.
+///   Empty if `n` is less than or equal to `1`.
+/// # Panics
+/// * If `n` is greater than [u64::MAX].
+/// # Example
+/// ```
+/// use pmath::factors::factorize;
+///
+/// assert_eq!(factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+/// assert!(factorize(1).is_empty());
+/// assert_eq!(
+///     factorize(18_446_743_979_220_271_189u64),
+///     vec![(4_294_967_279, 1), (4_294_967_291, 1)]
+/// );
+/// ```
+pub fn factorize<T: PrimInt>(n: T) -> Vec<(T, u32)> {
+    const TRIAL_LIMIT: u64 = 1000;
+
+    if n <= T::one() {
+        return Vec::new();
+    }
+    let mut n = n.to_u64().expect("n must not be greater than u64::MAX.");
+
+    let mut factors = Vec::new();
+    let mut divisor = 2;
+    while divisor < TRIAL_LIMIT && divisor <= n / divisor {
+        while n.is_multiple_of(divisor) {
+            factors.push(divisor);
+            n /= divisor;
+        }
+        // after 2, only odd numbers can be prime
+        divisor += if divisor == 2 { 1 } else { 2 };
+    }
+
+    // split the cofactors until only primes remain
+    let mut cofactors = vec![n];
+    while let Some(cofactor) = cofactors.pop() {
+        if cofactor == 1 {
+            continue;
+        } else if is_prime_mr(cofactor) {
+            factors.push(cofactor);
+        } else {
+            let divisor = pollard_brent(cofactor);
+            cofactors.push(divisor);
+            cofactors.push(cofactor / divisor);
+        }
+    }
+
+    factors.sort_unstable();
+    let mut factorization: Vec<(T, u32)> = Vec::new();
+    for factor in factors {
+        let factor = T::from(factor).unwrap();
+        match factorization.last_mut() {
+            Some((prime, exponent)) if *prime == factor => *exponent += 1,
+            _ => factorization.push((factor, 1)),
+        }
+    }
+    factorization
+}
+
+/// Find a nontrivial divisor of an odd composite integer with Brent's variant of Pollard's rho.
+///
+/// The pseudorandom sequence is `x -> x^2 + c (mod n)`, and `c` is increased
+/// whenever a sequence fails to give a divisor.
+fn pollard_brent(n: u64) -> u64 {
+    // the number of differences multiplied together between two GCD computations
+    const BATCH: u64 = 128;
+
+    let mul_mod = |a: u64, b: u64| (u128::from(a) * u128::from(b) % u128::from(n)) as u64;
+    for c in 1.. {
+        let step = |x: u64| ((u128::from(mul_mod(x, x)) + c) % u128::from(n)) as u64;
+
+        let (mut x, mut y, mut saved) = (2, 2, 2);
+        let (mut product, mut divisor) = (1, 1);
+        let mut length = 1;
+        while divisor == 1 {
+            x = y;
+            for _ in 0..length {
+                y = step(y);
+            }
+            let mut done = 0;
+            while done < length && divisor == 1 {
+                saved = y;
+                for _ in 0..BATCH.min(length - done) {
+                    y = step(y);
+                    product = mul_mod(product, x.abs_diff(y));
+                }
+                divisor = gcd(product, n);
+                done += BATCH;
+            }
+            length *= 2;
+        }
+
+        if divisor == n {
+            // the batch overshot, so repeat its steps one by one
+            loop {
+                saved = step(saved);
+                divisor = gcd(x.abs_diff(saved), n);
+                if divisor > 1 {
+                    break;
+                }
+            }
+        }
+        if divisor != n {
+            return divisor;
+        }
+    }
+    unreachable!("A divisor of a composite number is always found.")
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// The sum of all positive divisors of an integer.
+///
+/// Calculated from the prime factorization $n = \prod p_i^{e_i}$ as
+/// $\sigma(n) = \prod \frac{p_i^{e_i + 1} - 1}{p_i - 1}$.
+/// # Arguments
+/// * `n` - The integer to find the sum of divisors of.
+/// # Returns
+/// * The sum of all positive divisors of the integer, including the integer itself.
+/// # Panics
+/// * If `n` is not positive.
+/// * If `n` is greater than [u64::MAX].
+/// * If the sum overflows `T`.
+/// # Example
+/// ```
+/// use pmath::factors::sigma;
+///
+/// assert_eq!(sigma(1), 1);
+/// assert_eq!(sigma(12), 28);
+/// assert_eq!(sigma(28), 56);
+/// ```
+pub fn sigma<T: PrimInt>(n: T) -> T {
+    if n < T::one() {
+        panic!("Cannot find divisors of non-positive numbers.");
+    }
+    const OVERFLOW: &str = "The sum of divisors overflows.";
+
+    factorize(n)
+        .into_iter()
+        .map(|(prime, exponent)| {
+            // 1 + p + ... + p^e, which does not overflow before the result does
+            (0..exponent).fold(T::one(), |sum, _| {
+                sum.checked_mul(&prime)
+                    .and_then(|sum| sum.checked_add(&T::one()))
+                    .expect(OVERFLOW)
+            })
+        })
+        .fold(T::one(), |product, term| {
+            product.checked_mul(&term).expect(OVERFLOW)
+        })
+}
+
+/// The sum of the proper divisors of an integer.
+///
+/// Proper divisors are all divisors of an integer except the integer itself,
+/// so this is [sigma] of the integer minus the integer.
+/// # Arguments
+/// * `n` - The integer to find the sum of proper divisors of.
+/// # Returns
+/// * The sum of the proper divisors of the integer.
+/// # Panics
+/// * If `n` is not positive.
+/// * If `n` is greater than [u64::MAX].
+/// * If the sum of all divisors overflows `T`.
+/// # Example
+/// ```
+/// use pmath::factors::aliquot_sum;
+///
+/// assert_eq!(aliquot_sum(1), 0);
+/// assert_eq!(aliquot_sum(7), 1);
+/// assert_eq!(aliquot_sum(12), 16);
+/// ```
+pub fn aliquot_sum<T: PrimInt>(n: T) -> T {
+    sigma(n) - n
+}
+
+/// The classification of an integer by the sum of its proper divisors.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DivisorClass {
+    /// The sum of the proper divisors equals the integer.
+    Perfect,
+    /// The sum of the proper divisors is greater than the integer.
+    Abundant,
+    /// The sum of the proper divisors is less than the integer.
+    Deficient,
+}
+impl DivisorClass {
+    /// Classify an integer from the already known sum of its proper divisors.
+    ///
+    /// Useful with tables such as [sum_of_proper_divisors_0_to_n],
+    /// which are faster than calling [divisor_class] for every integer in a range.
+    /// # Arguments
+    /// * `n` - The integer to classify.
+    /// * `sum` - The sum of the proper divisors of the integer.
+    /// # Returns
+    /// * The class of the integer.
+    /// # Example
+    /// ```
+    /// use pmath::factors::{DivisorClass, sum_of_proper_divisors_0_to_n};
+    ///
+    /// let sums = sum_of_proper_divisors_0_to_n(12);
+    /// assert_eq!(DivisorClass::from_sum(6, sums[6]), DivisorClass::Perfect);
+    /// assert_eq!(DivisorClass::from_sum(12, sums[12]), DivisorClass::Abundant);
+    /// ```
+    pub fn from_sum<T: PrimInt>(n: T, sum: T) -> Self {
+        match sum.cmp(&n) {
+            Ordering::Equal => Self::Perfect,
+            Ordering::Greater => Self::Abundant,
+            Ordering::Less => Self::Deficient,
+        }
+    }
+}
+
+/// Classify an integer as perfect, abundant or deficient.
+///
+/// The integer is compared with the sum of its proper divisors given by [aliquot_sum].
+/// To classify all integers in a range, use [DivisorClass::from_sum] with a table of sums instead.
+/// # Arguments
+/// * `n` - The integer to classify.
+/// # Returns
+/// * The class of the integer.
+/// # Panics
+/// * If `n` is not positive.
+/// * If `n` is greater than [u64::MAX].
+/// * If the sum of all divisors overflows `T`.
+/// # Example
+/// ```
+/// use pmath::factors::{DivisorClass, divisor_class};
+///
+/// assert_eq!(divisor_class(6), DivisorClass::Perfect);
+/// assert_eq!(divisor_class(12), DivisorClass::Abundant);
+/// assert_eq!(divisor_class(1), DivisorClass::Deficient);
+/// ```
+pub fn divisor_class<T: PrimInt>(n: T) -> DivisorClass {
+    DivisorClass::from_sum(n, aliquot_sum(n))
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// The number of positive divisors of an integer.
+///
+/// Calculated from the prime factorization $n = \prod p_i^{e_i}$ as
+/// $\tau(n) = \prod (e_i + 1)$, without enumerating the divisors.
+/// # Arguments
+/// * `n` - The integer to find the number of divisors of.
+/// # Returns
+/// * The number of positive divisors of the integer, including `1` and the integer itself.
+/// # Panics
+/// * If `n` is not positive.
+/// * If `n` is greater than [u64::MAX].
+/// # Example
+/// ```
+/// use pmath::factors::num_divisors;
+///
+/// assert_eq!(num_divisors(1), 1);
+/// assert_eq!(num_divisors(12), 6);
+/// assert_eq!(num_divisors(76_576_500), 576);
+/// ```
+pub fn num_divisors<T: PrimInt>(n: T) -> usize {
+    if n < T::one() {
+        panic!("Cannot find divisors of non-positive numbers.");
+    }
+    factorize(n)
+        .into_iter()
+        .map(|(_, exponent)| exponent as usize + 1)
+        .product()
+}
+
+/// All positive divisors of an integer in ascending order.
+///
+/// The divisors are built from the prime factorization given by [factorize],
+/// so unlike [divisors], this is fast even for large integers with few divisors.
+/// # Arguments
+/// * `n` - The integer to find the divisors of.
+/// # Returns
+/// * The divisors of the integer in ascending order.
+/// # Panics
+/// * If `n` is not positive.
+/// * If `n` is greater than [u64::MAX].
+/// # Example
+/// ```
+/// use pmath::factors::sorted_divisors;
+///
+/// assert_eq!(sorted_divisors(1), vec![1]);
+/// assert_eq!(sorted_divisors(12), vec![1, 2, 3, 4, 6, 12]);
+/// assert_eq!(sorted_divisors(13), vec![1, 13]);
+/// ```
+pub fn sorted_divisors<T: PrimInt>(n: T) -> Vec<T> {
+    if n < T::one() {
+        panic!("Cannot find divisors of non-positive numbers.");
+    }
+    let mut divisors = vec![T::one()];
+    for (prime, exponent) in factorize(n) {
+        // multiply the divisors found so far by every power of the prime
+        let count = divisors.len();
+        let mut power = T::one();
+        for _ in 0..exponent {
+            power = power * prime;
+            divisors.extend_from_within(..count);
+            let len = divisors.len();
+            for divisor in &mut divisors[(len - count)..] {
+                *divisor = *divisor * power;
+            }
+        }
+    }
+    divisors.sort_unstable();
+    divisors
+}
+
+/// All proper divisors of an integer in ascending order.
+///
+/// Proper divisors are all divisors of an integer except the integer itself.
+/// # Arguments
+/// * `n` - The integer to find the proper divisors of.
+/// # Returns
+/// * The proper divisors of the integer in ascending order.
+/// # Panics
+/// * If `n` is not positive.
+/// * If `n` is greater than [u64::MAX].
+/// # Example
+/// ```
+/// use pmath::factors::sorted_proper_divisors;
+///
+/// assert!(sorted_proper_divisors(1).is_empty());
+/// assert_eq!(sorted_proper_divisors(12), vec![1, 2, 3, 4, 6]);
+/// assert_eq!(sorted_proper_divisors(13), vec![1]);
+/// ```
+pub fn sorted_proper_divisors<T: PrimInt>(n: T) -> Vec<T> {
+    let mut divisors = sorted_divisors(n);
+    divisors.pop();
+    divisors
+}
+
 /// An iterator over the divisors of an integer.
 ///
 /// Divisors are yielded in arbitrary order.
@@ -647,3 +989,197 @@ where
     }
     divisors
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn factorize_semiprime() {
+        //! Test the [factorize] on products of two primes close to `2^32`
+
+        assert_eq!(
+            factorize(18_446_743_979_220_271_189u64),
+            vec![(4_294_967_279, 1), (4_294_967_291, 1)]
+        );
+        assert_eq!(
+            factorize(18_446_744_030_759_878_681u64),
+            vec![(4_294_967_291, 2)]
+        );
+        assert_eq!(
+            factorize(1_000_003u64 * 999_983 * 1009),
+            vec![(1009, 1), (999_983, 1), (1_000_003, 1)]
+        );
+        assert_eq!(
+            factorize(u64::MAX),
+            vec![
+                (3, 1),
+                (5, 1),
+                (17, 1),
+                (257, 1),
+                (641, 1),
+                (65537, 1),
+                (6_700_417, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn factorize_reconstructs() {
+        //! Test that the product of the factors given by [factorize] is the original integer
+
+        let large = (0..1000).map(|i| u64::MAX - i * 1_000_000_007);
+        for n in (2..10_000).chain(large) {
+            let factorization = factorize(n);
+            assert!(factorization.iter().all(|&(prime, _)| is_prime_mr(prime)));
+            assert!(factorization.windows(2).all(|pair| pair[0].0 < pair[1].0));
+            let product = factorization
+                .iter()
+                .map(|&(prime, exponent)| prime.pow(exponent))
+                .product::<u64>();
+            assert_eq!(product, n);
+        }
+    }
+
+    #[test]
+    fn factorize_small() {
+        //! Test the [factorize] on integers without prime factors and against [distinct_prime_factors]
+
+        assert!(factorize(0).is_empty());
+        assert!(factorize(1).is_empty());
+        assert!(factorize(-12).is_empty());
+        assert_eq!(factorize(2u8), vec![(2, 1)]);
+        assert_eq!(factorize(1u64 << 63), vec![(2, 63)]);
+        for n in 2..2000u64 {
+            let expected = distinct_prime_factors(n)
+                .map(|(prime, count)| (prime, count as u32))
+                .collect::<Vec<_>>();
+            assert_eq!(factorize(n), expected);
+        }
+    }
+
+    #[test]
+    fn sigma_general() {
+        //! Test the [sigma] and [aliquot_sum] on perfect and amicable numbers and against a table
+
+        assert_eq!(sigma(28), 56);
+        assert_eq!(aliquot_sum(28), 28);
+        assert_eq!(aliquot_sum(220), 284);
+        assert_eq!(aliquot_sum(284), 220);
+        assert_eq!(sigma(1u8), 1);
+        assert_eq!(sigma(1u64 << 62), (1 << 63) - 1);
+
+        let sums = sum_of_proper_divisors_0_to_n(10_000u64);
+        for n in 1..=10_000 {
+            assert_eq!(aliquot_sum(n), sums[n as usize]);
+            assert_eq!(sigma(n), sums[n as usize] + n);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "The sum of divisors overflows.")]
+    fn sigma_overflow() {
+        //! Test that the [sigma] panics when the sum does not fit in the type, in every build profile
+
+        // sigma(25200) = 99944 does not fit in i16
+        sigma(25200i16);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sigma_zero() {
+        //! Test that the [sigma] panics when given zero
+
+        sigma(0);
+    }
+
+    #[test]
+    fn num_divisors_general() {
+        //! Test the [num_divisors] on small integers, primes and against a table
+
+        assert_eq!(num_divisors(28), 6);
+        assert_eq!(num_divisors(1), 1);
+        for prime in sieve_of_eratosthenes(10_000u64) {
+            assert_eq!(num_divisors(prime), 2);
+        }
+        assert_eq!(num_divisors(18_446_744_073_709_551_557u64), 2);
+        assert_eq!(num_divisors(1u64 << 63), 64);
+
+        let counts = num_of_divisors_0_to_n(10_000u64);
+        for n in 1..=10_000 {
+            assert_eq!(num_divisors(n), counts[n as usize] as usize);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn num_divisors_negative() {
+        //! Test that the [num_divisors] panics when given a negative integer
+
+        num_divisors(-6);
+    }
+
+    #[test]
+    fn sorted_divisors_general() {
+        //! Test the [sorted_divisors] and [sorted_proper_divisors] against [divisors]
+
+        assert_eq!(sorted_divisors(12), vec![1, 2, 3, 4, 6, 12]);
+        assert_eq!(sorted_proper_divisors(12), vec![1, 2, 3, 4, 6]);
+        assert_eq!(sorted_divisors(1), vec![1]);
+        assert!(sorted_proper_divisors(1).is_empty());
+        assert_eq!(
+            sorted_divisors(18_446_743_979_220_271_189u64),
+            vec![1, 4_294_967_279, 4_294_967_291, 18_446_743_979_220_271_189]
+        );
+
+        for n in 1..2000u64 {
+            let mut expected = divisors(n).collect::<Vec<_>>();
+            expected.sort_unstable();
+            assert_eq!(sorted_divisors(n), expected);
+            expected.pop();
+            assert_eq!(sorted_proper_divisors(n), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn sorted_divisors_zero() {
+        //! Test that the [sorted_divisors] panics when given zero
+
+        sorted_divisors(0);
+    }
+
+    #[test]
+    fn divisor_class_general() {
+        //! Test the [divisor_class] on small integers and perfect numbers
+
+        assert_eq!(divisor_class(6), DivisorClass::Perfect);
+        assert_eq!(divisor_class(28), DivisorClass::Perfect);
+        assert_eq!(divisor_class(12), DivisorClass::Abundant);
+        assert_eq!(divisor_class(8), DivisorClass::Deficient);
+        assert_eq!(divisor_class(1), DivisorClass::Deficient);
+        assert_eq!(divisor_class(945), DivisorClass::Abundant);
+        assert_eq!(
+            divisor_class(2_305_843_008_139_952_128u64),
+            DivisorClass::Perfect
+        );
+
+        let perfect = (1..10_000u64)
+            .filter(|&n| divisor_class(n) == DivisorClass::Perfect)
+            .collect::<Vec<_>>();
+        assert_eq!(perfect, vec![6, 28, 496, 8128]);
+        let sums = sum_of_proper_divisors_0_to_n(10_000u64);
+        for n in 1..=10_000 {
+            assert_eq!(
+                DivisorClass::from_sum(n, sums[n as usize]),
+                divisor_class(n)
+            );
+        }
+        // the first odd abundant number
+        assert!(
+            (1..945u64)
+                .step_by(2)
+                .all(|n| divisor_class(n) == DivisorClass::Deficient)
+        );
+    }
+}
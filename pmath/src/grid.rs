@@ -0,0 +1,273 @@
+//! Path sums through triangles and grids of numbers.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// The maximum sum of a path from the top to the bottom of a triangle.
+///
+/// The path starts at the top and moves to one of the two adjacent numbers in the row below.
+/// Rows are reduced from the bottom up, so the running time is linear in the number of elements.
+/// # Arguments
+/// * `triangle` - The rows of the triangle, where the row at index `i` has `i + 1` numbers.
+/// # Returns
+/// * The maximum path sum, or `0` if the triangle is empty.
+/// # Panics
+/// * If a row does not have exactly one more number than the row above it.
+/// # Example
+/// ```
+/// use pmath::grid::max_triangle_path;
+///
+/// //    3
+/// //   7 4
+/// //  2 4 6
+/// // 8 5 9 3
+/// let triangle = vec![vec![3], vec![7, 4], vec![2, 4, 6], vec![8, 5, 9, 3]];
+/// // 3 + 7 + 4 + 9
+/// assert_eq!(max_triangle_path(&triangle), 23);
+/// ```
+pub fn max_triangle_path(triangle: &[Vec<u64>]) -> u64 {
+    for (i, row) in triangle.iter().enumerate() {
+        if row.len() != i + 1 {
+            panic!("Row {i} of the triangle must have {} numbers.", i + 1);
+        }
+    }
+
+    let Some((last_row, rows)) = triangle.split_last() else {
+        return 0;
+    };
+
+    // best[j] is the maximum path sum from the j-th number of the current row to the bottom
+    let mut best = last_row.clone();
+    for row in rows.iter().rev() {
+        for (j, &value) in row.iter().enumerate() {
+            best[j] = value + best[j].max(best[j + 1]);
+        }
+        best.pop();
+    }
+    best[0]
+}
+
+/// The minimum sum of a path from the top left to the bottom right cell of a grid,
+/// moving only right and down.
+/// # Arguments
+/// * `grid` - The rows of the grid.
+/// # Returns
+/// * The minimum path sum, or `0` if the grid is empty.
+/// # Panics
+/// * If the rows of the grid do not have the same length.
+/// # Example
+/// ```
+/// use pmath::grid::min_path_two_ways;
+///
+/// let grid = vec![vec![131, 673, 234], vec![201, 96, 342], vec![630, 803, 746]];
+/// // 131 + 201 + 96 + 342 + 746
+/// assert_eq!(min_path_two_ways(&grid), 1516);
+/// ```
+pub fn min_path_two_ways(grid: &[Vec<u64>]) -> u64 {
+    if grid_dimensions(grid).is_none() {
+        return 0;
+    }
+
+    // best[j] is the minimum path sum from the top left cell to the j-th cell of the current row
+    let mut best = Vec::with_capacity(grid[0].len());
+    for row in grid {
+        for (j, &value) in row.iter().enumerate() {
+            let from_above = best.get(j).copied();
+            let from_left = j.checked_sub(1).map(|left| best[left]);
+            let previous = match (from_above, from_left) {
+                (Some(above), Some(left)) => above.min(left),
+                (Some(previous), None) | (None, Some(previous)) => previous,
+                (None, None) => 0,
+            };
+            if j < best.len() {
+                best[j] = previous + value;
+            } else {
+                best.push(previous + value);
+            }
+        }
+    }
+    best[best.len() - 1]
+}
+
+/// The minimum sum of a path from any cell in the left column to any cell in the right column
+/// of a grid, moving only up, down and right.
+/// # Arguments
+/// * `grid` - The rows of the grid.
+/// # Returns
+/// * The minimum path sum, or `0` if the grid is empty.
+/// # Panics
+/// * If the rows of the grid do not have the same length.
+/// # Example
+/// ```
+/// use pmath::grid::min_path_three_ways;
+///
+/// let grid = vec![vec![131, 673, 234], vec![201, 96, 342], vec![630, 803, 746]];
+/// // 201 + 96 + 342
+/// assert_eq!(min_path_three_ways(&grid), 639);
+/// ```
+pub fn min_path_three_ways(grid: &[Vec<u64>]) -> u64 {
+    let Some((rows, columns)) = grid_dimensions(grid) else {
+        return 0;
+    };
+
+    // best[i] is the minimum path sum from the left column to the i-th cell of the current column
+    let mut best = grid.iter().map(|row| row[0]).collect::<Vec<_>>();
+    for j in 1..columns {
+        // enter each cell from the left, then relax the moves down and up the column
+        for i in 0..rows {
+            best[i] += grid[i][j];
+        }
+        for i in 1..rows {
+            best[i] = best[i].min(best[i - 1] + grid[i][j]);
+        }
+        for i in (0..(rows - 1)).rev() {
+            best[i] = best[i].min(best[i + 1] + grid[i][j]);
+        }
+    }
+    best.into_iter().min().unwrap()
+}
+
+/// The minimum sum of a path from the top left to the bottom right cell of a grid,
+/// moving up, down, left and right.
+///
+/// Dijkstra's algorithm is used, since paths can turn back in any direction.
+/// # Arguments
+/// * `grid` - The rows of the grid.
+/// # Returns
+/// * The minimum path sum, or `0` if the grid is empty.
+/// # Panics
+/// * If the rows of the grid do not have the same length.
+/// # Example
+/// ```
+/// use pmath::grid::min_path_four_ways;
+///
+/// let grid = vec![vec![1, 9, 1, 1, 1], vec![1, 9, 1, 9, 1], vec![1, 1, 1, 9, 1]];
+/// // the path goes down, right, up, right and down again
+/// assert_eq!(min_path_four_ways(&grid), 11);
+/// ```
+pub fn min_path_four_ways(grid: &[Vec<u64>]) -> u64 {
+    let Some((rows, columns)) = grid_dimensions(grid) else {
+        return 0;
+    };
+
+    let mut best = vec![vec![u64::MAX; columns]; rows];
+    let mut queue = BinaryHeap::new();
+    best[0][0] = grid[0][0];
+    queue.push(Reverse((grid[0][0], 0, 0)));
+
+    while let Some(Reverse((sum, i, j))) = queue.pop() {
+        if (i, j) == (rows - 1, columns - 1) {
+            return sum;
+        }
+        if sum > best[i][j] {
+            // a shorter path to this cell was already processed
+            continue;
+        }
+        let neighbours = [
+            i.checked_sub(1).map(|up| (up, j)),
+            (i + 1 < rows).then_some((i + 1, j)),
+            j.checked_sub(1).map(|left| (i, left)),
+            (j + 1 < columns).then_some((i, j + 1)),
+        ];
+        for (next_i, next_j) in neighbours.into_iter().flatten() {
+            let next_sum = sum + grid[next_i][next_j];
+            if next_sum < best[next_i][next_j] {
+                best[next_i][next_j] = next_sum;
+                queue.push(Reverse((next_sum, next_i, next_j)));
+            }
+        }
+    }
+    unreachable!("The bottom right cell is always reachable.")
+}
+
+/// The number of rows and columns of a grid, or [None] if it is empty.
+/// # Panics
+/// * If the rows of the grid do not have the same length.
+fn grid_dimensions(grid: &[Vec<u64>]) -> Option<(usize, usize)> {
+    let columns = grid.first()?.len();
+    if grid.iter().any(|row| row.len() != columns) {
+        panic!("All rows of the grid must have the same length.");
+    }
+    (columns != 0).then_some((grid.len(), columns))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The example grid from the *Project Euler* problems 81, 82 and 83.
+    fn example_grid() -> Vec<Vec<u64>> {
+        vec![
+            vec![131, 673, 234, 103, 18],
+            vec![201, 96, 342, 965, 150],
+            vec![630, 803, 746, 422, 111],
+            vec![537, 699, 497, 121, 956],
+            vec![805, 732, 524, 37, 331],
+        ]
+    }
+
+    #[test]
+    fn max_triangle_path_small() {
+        //! Test the [max_triangle_path] on small triangles.
+
+        assert_eq!(max_triangle_path(&[]), 0);
+        assert_eq!(max_triangle_path(&[vec![5]]), 5);
+        assert_eq!(max_triangle_path(&[vec![1], vec![2, 3]]), 4);
+        assert_eq!(
+            max_triangle_path(&[vec![3], vec![7, 4], vec![2, 4, 6], vec![8, 5, 9, 3]]),
+            23
+        );
+        // the greedy choice at the top is not the best one
+        assert_eq!(
+            max_triangle_path(&[vec![1], vec![5, 1], vec![1, 1, 1], vec![1, 1, 1, 100]]),
+            103
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn max_triangle_path_malformed() {
+        //! Test that the [max_triangle_path] panics when a row has the wrong length.
+
+        max_triangle_path(&[vec![1], vec![2, 3, 4]]);
+    }
+
+    #[test]
+    fn min_path_example() {
+        //! Test the minimum path sums on the example grid.
+
+        let grid = example_grid();
+        assert_eq!(min_path_two_ways(&grid), 2427);
+        assert_eq!(min_path_three_ways(&grid), 994);
+        assert_eq!(min_path_four_ways(&grid), 2297);
+    }
+
+    #[test]
+    fn min_path_small() {
+        //! Test the minimum path sums on empty, single cell and single line grids.
+
+        for min_path in [min_path_two_ways, min_path_three_ways, min_path_four_ways] {
+            assert_eq!(min_path(&[]), 0);
+            assert_eq!(min_path(&[vec![]]), 0);
+            assert_eq!(min_path(&[vec![7]]), 7);
+        }
+
+        let row = [vec![1, 2, 3]];
+        assert_eq!(min_path_two_ways(&row), 6);
+        assert_eq!(min_path_three_ways(&row), 6);
+        assert_eq!(min_path_four_ways(&row), 6);
+
+        let column = [vec![1], vec![2], vec![3]];
+        assert_eq!(min_path_two_ways(&column), 6);
+        assert_eq!(min_path_three_ways(&column), 1);
+        assert_eq!(min_path_four_ways(&column), 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn min_path_not_rectangular() {
+        //! Test that the minimum path sums panic when the rows have different lengths.
+
+        min_path_four_ways(&[vec![1, 2], vec![3]]);
+    }
+}
@@ -1,9 +1,12 @@
 #![doc = include_str!("../README.md")]
 
+pub mod date;
 pub mod digits;
 pub mod factors;
 pub mod geometry;
+pub mod grid;
 pub mod linalg;
+pub mod prelude;
 pub mod primes;
 pub mod probability;
 pub mod sequences;
@@ -11,17 +14,39 @@ pub mod statistics;
 
 use std::borrow::Borrow;
 use std::collections::HashSet;
+use std::error::Error as StdError;
+use std::fmt::{self, Display, Formatter};
 use std::hash::Hash;
 use std::iter;
 use std::mem;
 
+use digits::is_permutation;
 use factors::distinct_prime_factors;
-use malachite::Integer;
+use malachite::base::num::arithmetic::traits::{Abs, FloorSqrt};
 use malachite::base::num::basic::traits::{One, Zero};
 use malachite::rational::Rational;
+use malachite::{Integer, Natural};
 use num_traits::{ConstOne, ConstZero, Euclid, PrimInt, Signed, ToPrimitive};
 use primes::sieve_of_eratosthenes;
 
+/// An enum representing errors that can occur in this crate.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum PmathError {
+    /// An argument that must not be negative is negative.
+    NegativeArgument,
+    /// A modulus that must be positive is not positive.
+    NonPositiveModulus,
+}
+impl Display for PmathError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NegativeArgument => write!(f, "The argument must not be negative."),
+            Self::NonPositiveModulus => write!(f, "The modulus must be positive."),
+        }
+    }
+}
+impl StdError for PmathError {}
+
 #[cfg_attr(doc, katexit::katexit)]
 /// Simple continued fraction.
 ///
@@ -79,7 +104,7 @@ pub struct SimpleContinuedFraction<T> {
 }
 impl<T> SimpleContinuedFraction<T>
 where
-    T: PrimInt + ConstZero + ConstOne + Into<Integer>,
+    T: Clone,
 {
     /// Create a new simple continued fraction.
     /// # Arguments
@@ -92,14 +117,95 @@ where
         U: IntoIterator<Item = V>,
         V: Borrow<T>,
     {
-        let non_periodic = non_periodic.into_iter().map(|x| *x.borrow()).collect();
-        let periodic = periodic.map(|p| p.into_iter().map(|x| *x.borrow()).collect());
+        let non_periodic = non_periodic
+            .into_iter()
+            .map(|x| x.borrow().clone())
+            .collect();
+        let periodic = periodic.map(|p| p.into_iter().map(|x| x.borrow().clone()).collect());
         Self {
             non_periodic,
             periodic,
         }
     }
 
+    /// The non-repeating coefficients of the continued fraction.
+    /// # Returns
+    /// * A slice of the non-repeating coefficients.
+    pub fn non_periodic(&self) -> &[T] {
+        &self.non_periodic
+    }
+
+    /// The repeating coefficients of the continued fraction, if any.
+    /// # Returns
+    /// * An [Option] containing a slice of the repeating coefficients.
+    pub fn periodic(&self) -> Option<&[T]> {
+        self.periodic.as_deref()
+    }
+
+    /// The convergents of the continued fraction.
+    ///
+    /// These are the fractions that approximate the value of the continued fraction,
+    /// and are generated by taking the coefficients of the continued fraction:
+    /// $$
+    ///     \\begin{align*}
+    ///         &a\_0 \\\\
+    ///         &a\_0 + \\frac{1}{a\_1} \\\\
+    ///         &a\_0 + \\frac{1}{a\_1 + \\frac{1}{a\_2}} \\\\
+    ///         &a\_0 + \\frac{1}{a\_1 + \\frac{1}{a\_2 + \\frac{1}{a\_3}}} \\\\
+    ///         &\\vdots
+    ///     \\end{align*}
+    /// $$
+    /// Each subsequent convergent uses one more coefficient than the previous one
+    /// therefore better approximating the value of the continued fraction.
+    /// # Returns
+    /// * An iterator over the convergents of the continued fraction.
+    ///   If the continued fraction is finite, the iterator ends with
+    ///   the exact value of the continued fraction, and
+    ///   if it is infinite, the iterator continues indefinitely,
+    ///   producing fractions that better and better approximate the value of the continued fraction.
+    /// # Example
+    /// ```
+    /// use pmath::SimpleContinuedFraction;
+    /// use malachite::rational::Rational;
+    ///
+    /// let cf = SimpleContinuedFraction::new(vec![1, 2], Some(vec![3, 4]));
+    /// let mut convergents = cf.convergents();
+    /// assert_eq!(convergents.next().unwrap(), Rational::const_from_unsigneds(1, 1));
+    /// assert_eq!(convergents.next().unwrap(), Rational::const_from_unsigneds(3, 2));
+    /// assert_eq!(convergents.next().unwrap(), Rational::const_from_unsigneds(10, 7));
+    /// assert_eq!(convergents.next().unwrap(), Rational::const_from_unsigneds(43, 30));
+    /// assert_eq!(convergents.next().unwrap(), Rational::const_from_unsigneds(139, 97));
+    /// assert_eq!(convergents.next().unwrap(), Rational::const_from_unsigneds(599, 418));
+    /// // ... and so on (infinitely)
+    /// ```
+    pub fn convergents(&self) -> impl Iterator<Item = Rational>
+    where
+        T: Into<Integer>,
+    {
+        let mut prev_num = Integer::ZERO;
+        let mut prev_den = Integer::ONE;
+        let mut num = Integer::ONE;
+        let mut den = Integer::ZERO;
+        let mut values = self
+            .non_periodic
+            .iter()
+            .chain(self.periodic.iter().flat_map(|v| v.iter().cycle()));
+
+        iter::from_fn(move || {
+            let next_value = values.next()?;
+            let next_value: Integer = next_value.clone().into();
+            let next_num = &next_value * &num + &prev_num;
+            let next_den = next_value * &den + &prev_den;
+            prev_num = mem::replace(&mut num, next_num);
+            prev_den = mem::replace(&mut den, next_den);
+            Some(Rational::from_integers_ref(&num, &den))
+        })
+    }
+}
+impl<T> SimpleContinuedFraction<T>
+where
+    T: PrimInt + ConstZero + ConstOne,
+{
     /// Create a new simple continued fraction of the square root of an integer.
     /// # Arguments
     /// * `n` - The integer to create the continued fraction of its square root.
@@ -158,75 +264,203 @@ where
             periodic,
         }
     }
-
-    /// The non-repeating coefficients of the continued fraction.
-    /// # Returns
-    /// * A slice of the non-repeating coefficients.
-    pub fn non_periodic(&self) -> &[T] {
-        &self.non_periodic
-    }
-
-    /// The repeating coefficients of the continued fraction, if any.
-    /// # Returns
-    /// * An [Option] containing a slice of the repeating coefficients.
-    pub fn periodic(&self) -> Option<&[T]> {
-        self.periodic.as_deref()
-    }
-
-    /// The convergents of the continued fraction.
+}
+impl SimpleContinuedFraction<Integer> {
+    /// Create a new simple continued fraction of the square root of a big integer.
     ///
-    /// These are the fractions that approximate the value of the continued fraction,
-    /// and are generated by taking the coefficients of the continued fraction:
-    /// $$
-    ///     \\begin{align*}
-    ///         &a\_0 \\\\
-    ///         &a\_0 + \\frac{1}{a\_1} \\\\
-    ///         &a\_0 + \\frac{1}{a\_1 + \\frac{1}{a\_2}} \\\\
-    ///         &a\_0 + \\frac{1}{a\_1 + \\frac{1}{a\_2 + \\frac{1}{a\_3}}} \\\\
-    ///         &\\vdots
-    ///     \\end{align*}
-    /// $$
-    /// Each subsequent convergent uses one more coefficient than the previous one
-    /// therefore better approximating the value of the continued fraction.
+    /// Same as [SimpleContinuedFraction::from_sqrt], but works with integers of any size.
+    /// The period is detected by its last coefficient, which is always twice the first one.
+    /// # Arguments
+    /// * `n` - The integer to create the continued fraction of its square root.
     /// # Returns
-    /// * An iterator over the convergents of the continued fraction.
-    ///   If the continued fraction is finite, the iterator ends with
-    ///   the exact value of the continued fraction, and
-    ///   if it is infinite, the iterator continues indefinitely,
-    ///   producing fractions that better and better approximate the value of the continued fraction.
+    /// * A new simple continued fraction representing the square root of the integer.
+    /// # Panics
+    /// * If `n` is negative.
     /// # Example
     /// ```
+    /// use malachite::Integer;
     /// use pmath::SimpleContinuedFraction;
-    /// use malachite::rational::Rational;
     ///
-    /// let cf = SimpleContinuedFraction::new(vec![1, 2], Some(vec![3, 4]));
-    /// let mut convergents = cf.convergents();
-    /// assert_eq!(convergents.next().unwrap(), Rational::const_from_unsigneds(1, 1));
-    /// assert_eq!(convergents.next().unwrap(), Rational::const_from_unsigneds(3, 2));
-    /// assert_eq!(convergents.next().unwrap(), Rational::const_from_unsigneds(10, 7));
-    /// assert_eq!(convergents.next().unwrap(), Rational::const_from_unsigneds(43, 30));
-    /// assert_eq!(convergents.next().unwrap(), Rational::const_from_unsigneds(139, 97));
-    /// assert_eq!(convergents.next().unwrap(), Rational::const_from_unsigneds(599, 418));
-    /// // ... and so on (infinitely)
+    /// // sqrt(10^40 + 1) = [10^20; 2 * 10^20, 2 * 10^20, ...]
+    /// let root = Integer::from(10u128.pow(20));
+    /// let cf = SimpleContinuedFraction::from_sqrt_integer(&(&root * &root + Integer::from(1)));
+    /// assert_eq!(cf.non_periodic(), [root.clone()].as_slice());
+    /// assert_eq!(cf.periodic(), Some([root * Integer::from(2)].as_slice()));
     /// ```
-    pub fn convergents(&self) -> impl Iterator<Item = Rational> {
-        let mut prev_num = Integer::ZERO;
-        let mut prev_den = Integer::ONE;
-        let mut num = Integer::ONE;
-        let mut den = Integer::ZERO;
-        let mut values = self
-            .non_periodic
-            .iter()
-            .chain(self.periodic.iter().flat_map(|v| v.iter().cycle()));
+    pub fn from_sqrt_integer(n: &Integer) -> Self {
+        if *n < 0 {
+            panic!("Cannot calculate square root of a negative integer.");
+        }
 
-        iter::from_fn(move || {
-            let next_value = values.next()?;
-            let next_num = (*next_value).into() * &num + &prev_num;
-            let next_den = (*next_value).into() * &den + &prev_den;
-            prev_num = mem::replace(&mut num, next_num);
-            prev_den = mem::replace(&mut den, next_den);
-            Some(Rational::from_integers_ref(&num, &den))
-        })
+        let root = n.floor_sqrt();
+        let mut periodic = None;
+
+        // if n is not a perfect square, then find the periodic part of the continued fraction
+        if &root * &root != *n {
+            let last = &root << 1u32;
+            let mut coefficients = Vec::new();
+
+            // the same recurrence as in from_sqrt, starting with the coefficient a_0
+            let mut num = Integer::ZERO;
+            let mut denom = Integer::ONE;
+            let mut expanded_val = root.clone();
+            while expanded_val != last {
+                num = &denom * &expanded_val - num;
+                denom = (n - &num * &num) / denom;
+                expanded_val = (&root + &num) / &denom;
+                coefficients.push(expanded_val.clone());
+            }
+            periodic = Some(coefficients);
+        }
+
+        Self {
+            non_periodic: vec![root],
+            periodic,
+        }
+    }
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// The continued fraction of $e$.
+///
+/// The coefficients of $e$ follow the pattern $\left[ 2; 1, 2, 1, 1, 4, 1, 1, 6, \ldots \right]$,
+/// which is not periodic, so the continued fraction is truncated to a finite one.
+/// # Arguments
+/// * `terms` - The number of coefficients to generate.
+/// # Returns
+/// * A finite simple continued fraction with the first `terms` coefficients of $e$.
+/// # Example
+/// ```
+/// use malachite::rational::Rational;
+/// use pmath::e_continued_fraction;
+///
+/// let cf = e_continued_fraction(5);
+/// assert_eq!(cf.non_periodic(), vec![2, 1, 2, 1, 1].as_slice());
+/// assert_eq!(cf.periodic(), None);
+/// // the last convergent is 87/32
+/// assert_eq!(cf.convergents().last().unwrap(), Rational::const_from_unsigneds(87, 32));
+/// ```
+pub fn e_continued_fraction(terms: usize) -> SimpleContinuedFraction<u64> {
+    let coefficients = (0..terms as u64).map(|i| match i {
+        0 => 2,
+        // every third coefficient is the next even number
+        i if i % 3 == 2 => 2 * (i + 1) / 3,
+        _ => 1,
+    });
+    SimpleContinuedFraction::new(coefficients, None)
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// The fundamental solution of Pell's equation.
+///
+/// Pell's equation is the equation $x^2 - d \cdot y^2 = 1$.
+/// Its fundamental solution is the solution in positive integers with the smallest $x$.
+/// It is one of the convergents of the continued fraction of $\sqrt{d}$:
+/// the one ending the first period if its length is even,
+/// or the one ending the second period if its length is odd.
+/// # Arguments
+/// * `d` - The coefficient of the equation.
+/// # Returns
+/// * The fundamental solution $(x, y)$, or [None] if `d` is a perfect square
+///   (then the equation has no solution in positive integers).
+/// # Example
+/// ```
+/// use malachite::Integer;
+/// use pmath::pell_fundamental;
+///
+/// // 649^2 - 13 * 180^2 = 1
+/// assert_eq!(pell_fundamental(13), Some((Integer::from(649), Integer::from(180))));
+/// assert_eq!(pell_fundamental(16), None);
+/// ```
+pub fn pell_fundamental(d: u64) -> Option<(Integer, Integer)> {
+    // the exact integer root is needed, since f64 cannot represent every d above 2^53
+    let continued_fraction = SimpleContinuedFraction::from_sqrt_integer(&Integer::from(d));
+    let period = continued_fraction.periodic()?.len();
+    let index = if period.is_multiple_of(2) {
+        period - 1
+    } else {
+        2 * period - 1
+    };
+
+    let convergent = continued_fraction.convergents().nth(index).unwrap();
+    Some((
+        Integer::from(convergent.numerator_ref()),
+        Integer::from(convergent.denominator_ref()),
+    ))
+}
+
+/// The best rational approximation of a number with a bounded denominator.
+///
+/// The candidates are the convergents of the continued fraction of `target`
+/// and the semiconvergents between the last two convergents within the bound.
+/// The number is expanded exactly, as every finite [f64] is a rational number.
+/// # Arguments
+/// * `target` - The number to approximate.
+/// * `max_denominator` - The largest allowed denominator.
+/// # Returns
+/// * The closest fraction to `target` as a `(numerator, denominator)` pair,
+///   with the denominator not exceeding `max_denominator`.
+/// # Panics
+/// * If `target` is not finite.
+/// * If `max_denominator` is `0`.
+/// * If the numerator does not fit in an [i64].
+/// # Example
+/// ```
+/// use pmath::best_rational_approximation;
+/// use std::f64::consts::PI;
+///
+/// assert_eq!(best_rational_approximation(PI, 10), (22, 7));
+/// assert_eq!(best_rational_approximation(PI, 1000), (355, 113));
+/// ```
+pub fn best_rational_approximation(target: f64, max_denominator: u64) -> (i64, u64) {
+    if max_denominator == 0 {
+        panic!("The maximum denominator must be positive.");
+    }
+    let exact = Rational::try_from(target.abs()).expect("Cannot approximate a non-finite number.");
+    let max_denominator = Natural::from(max_denominator);
+
+    let (numerator, denominator) = if *exact.denominator_ref() <= max_denominator {
+        (
+            exact.numerator_ref().clone(),
+            exact.denominator_ref().clone(),
+        )
+    } else {
+        // the last two convergents within the bound
+        let (mut p0, mut q0, mut p1, mut q1) =
+            (Natural::ZERO, Natural::ONE, Natural::ONE, Natural::ZERO);
+        // the remaining part of the number, as a fraction n / d
+        let mut n = exact.numerator_ref().clone();
+        let mut d = exact.denominator_ref().clone();
+        loop {
+            let a = &n / &d;
+            let q2 = &q0 + &a * &q1;
+            if q2 > max_denominator {
+                break;
+            }
+            (p0, q0, p1, q1) = (p1.clone(), q1, p0 + &a * p1, q2);
+            (n, d) = (d.clone(), n - a * d);
+        }
+
+        // the largest semiconvergent within the bound
+        let k = (&max_denominator - &q0) / &q1;
+        let semiconvergent = (p0 + &k * &p1, q0 + k * &q1);
+        let distance = |(p, q): &(Natural, Natural)| {
+            (Rational::from_naturals(p.clone(), q.clone()) - &exact).abs()
+        };
+        let convergent = (p1, q1);
+        if distance(&convergent) <= distance(&semiconvergent) {
+            convergent
+        } else {
+            semiconvergent
+        }
+    };
+
+    let numerator = i64::try_from(&numerator).expect("The numerator does not fit in i64.");
+    let denominator = u64::try_from(&denominator).unwrap();
+    if target < 0.0 {
+        (-numerator, denominator)
+    } else {
+        (numerator, denominator)
     }
 }
 
@@ -280,6 +514,96 @@ where
     panic!("a and n are not coprime.");
 }
 
+#[cfg_attr(doc, katexit::katexit)]
+/// Modular exponentiation.
+///
+/// Calculates $base^{exp} \bmod modulus$ using binary exponentiation.
+/// Intermediate products are calculated in [u128] and reduced modulo `modulus`,
+/// so they never overflow.
+/// # Arguments
+/// * `base` - The base.
+/// * `exp` - The exponent.
+/// * `modulus` - The modulus.
+/// # Returns
+/// * The power reduced into the range `0..modulus`.
+/// # Panics
+/// * If `modulus` is not positive.
+/// * If `modulus` is greater than [u64::MAX].
+/// * If `exp` is negative.
+/// # Example
+/// ```
+/// use pmath::mod_pow;
+///
+/// assert_eq!(mod_pow(2, 10, 1000), 24);
+/// assert_eq!(mod_pow(2, 90, 97), 47);
+/// // last ten digits of 2^7830457
+/// assert_eq!(mod_pow(2u64, 7_830_457, 10_000_000_000), 9_700_303_872);
+/// ```
+pub fn mod_pow<T>(base: T, exp: T, modulus: T) -> T
+where
+    T: PrimInt + ConstZero + ConstOne,
+{
+    if modulus <= T::ZERO {
+        panic!("Modulus must be positive.");
+    }
+    if exp < T::ZERO {
+        panic!("Exponent must not be negative.");
+    }
+    let modulus = modulus.to_u128().unwrap();
+    if modulus > u128::from(u64::MAX) {
+        panic!("Modulus must not be greater than u64::MAX.");
+    }
+
+    // base reduced into the range 0..modulus, also for negative bases
+    let mut base = base.to_i128().map_or_else(
+        || base.to_u128().unwrap() % modulus,
+        |base| base.rem_euclid(modulus as i128) as u128,
+    );
+    let mut exp = exp.to_u128().unwrap();
+    let mut result = 1 % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    T::from(result).unwrap()
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Modular multiplicative inverse.
+///
+/// The inverse of $a$ modulo $n$ is the integer $x$ such that $a \cdot x \equiv 1 \pmod n$.
+/// It exists only if $a$ and $n$ are coprime.
+/// Calculated from the coefficients of Bézout's identity given by [gcd_extended].
+/// # Arguments
+/// * `a` - The integer to invert.
+/// * `n` - The modulus.
+/// # Returns
+/// * The inverse reduced into the range `0..n`, or [None] if `a` and `n` are not coprime.
+/// # Panics
+/// * If `n` is not positive.
+/// # Example
+/// ```
+/// use pmath::mod_inverse;
+///
+/// // 3 * 5 = 15 = 2 * 7 + 1
+/// assert_eq!(mod_inverse(3, 7), Some(5));
+/// // 4 and 6 share the factor 2
+/// assert_eq!(mod_inverse(4, 6), None);
+/// ```
+pub fn mod_inverse<T>(a: T, n: T) -> Option<T>
+where
+    T: PrimInt + ConstZero + ConstOne + Signed + Euclid,
+{
+    if n <= T::ZERO {
+        panic!("Modulus must be positive.");
+    }
+    let (gcd, x, _) = gcd_extended(a.rem_euclid(&n), n);
+    (gcd == T::ONE).then(|| x.rem_euclid(&n))
+}
+
 #[cfg_attr(doc, katexit::katexit)]
 /// Partition function.
 ///
@@ -547,13 +871,36 @@ where
 /// assert_eq!(isqrt(12), 3);
 /// ```
 pub fn isqrt<T>(n: T) -> T
+where
+    T: PrimInt + ConstZero + ConstOne,
+{
+    try_isqrt(n).expect("Cannot calculate square root of a negative integer.")
+}
+
+/// Integer square root.
+///
+/// Same as [isqrt], but returns an error instead of panicking.
+/// # Arguments
+/// * `n` - The integer to find the integer square root of.
+/// # Returns
+/// * The integer square root.
+/// # Errors
+/// * [PmathError::NegativeArgument] - If `n` is negative.
+/// # Example
+/// ```
+/// use pmath::{PmathError, try_isqrt};
+///
+/// assert_eq!(try_isqrt(12), Ok(3));
+/// assert_eq!(try_isqrt(-12), Err(PmathError::NegativeArgument));
+/// ```
+pub fn try_isqrt<T>(n: T) -> Result<T, PmathError>
 where
     T: PrimInt + ConstZero + ConstOne,
 {
     if n < T::ZERO {
-        panic!("Cannot calculate square root of a negative integer.");
+        Err(PmathError::NegativeArgument)
     } else if n <= T::ONE {
-        n
+        Ok(n)
     } else {
         let t2 = T::from(2).unwrap();
         let mut x0 = t2.pow((n.to_u128().unwrap().ilog2() / 2) + 1);
@@ -562,7 +909,7 @@ where
             x0 = x1;
             x1 = (x0 + n / x0) / t2;
         }
-        x0
+        Ok(x0)
     }
 }
 
@@ -588,12 +935,36 @@ where
 /// // gcd of 0 and 5 is 5
 /// assert_eq!(gcd(0, 5), 5);
 /// ```
-pub fn gcd<T>(mut num1: T, mut num2: T) -> T
+pub fn gcd<T>(num1: T, num2: T) -> T
+where
+    T: PrimInt + ConstZero,
+{
+    try_gcd(num1, num2).expect("Cannot calculate GCD of negative numbers.")
+}
+
+/// The greatest common divisor of two integers.
+///
+/// Same as [gcd], but returns an error instead of panicking.
+/// # Arguments
+/// * `num1` - The first integer.
+/// * `num2` - The second integer.
+/// # Returns
+/// * The greatest common divisor.
+/// # Errors
+/// * [PmathError::NegativeArgument] - If either of the integers is negative.
+/// # Example
+/// ```
+/// use pmath::{PmathError, try_gcd};
+///
+/// assert_eq!(try_gcd(12, 18), Ok(6));
+/// assert_eq!(try_gcd(-12, 18), Err(PmathError::NegativeArgument));
+/// ```
+pub fn try_gcd<T>(mut num1: T, mut num2: T) -> Result<T, PmathError>
 where
     T: PrimInt + ConstZero,
 {
     if num1 < T::ZERO || num2 < T::ZERO {
-        panic!("Cannot calculate GCD of negative numbers.");
+        return Err(PmathError::NegativeArgument);
     }
     if num1 < num2 {
         (num1, num2) = (num2, num1);
@@ -601,7 +972,7 @@ where
     while num2 > T::ZERO {
         (num1, num2) = (num2, num1 % num2);
     }
-    num1
+    Ok(num1)
 }
 
 /// The greatest common divisor of multiple integers.
@@ -884,6 +1255,49 @@ where
     phi_values
 }
 
+#[cfg_attr(doc, katexit::katexit)]
+/// The ratio of an integer to its Euler's totient function, $\frac{n}{\varphi(n)}$.
+///
+/// The ratio is large for integers with many small distinct prime factors
+/// and close to `1` for primes.
+/// # Arguments
+/// * `n` - The integer to calculate the ratio of.
+/// # Returns
+/// * The ratio `n / phi(n)`.
+/// # Panics
+/// * If `n` is `0`.
+/// # Example
+/// ```
+/// use pmath::totient_ratio;
+///
+/// // phi(6) = 2
+/// assert_eq!(totient_ratio(6), 3.0);
+/// ```
+pub fn totient_ratio(n: u64) -> f64 {
+    if n == 0 {
+        panic!("The totient ratio is not defined for 0.");
+    }
+    n as f64 / phi(n) as f64
+}
+
+/// Check whether the Euler's totient function of an integer is a permutation of its digits.
+/// # Arguments
+/// * `n` - The integer to check.
+/// # Returns
+/// * Whether `phi(n)` is a permutation of the decimal digits of `n`.
+/// # Example
+/// ```
+/// use pmath::is_totient_permutation;
+///
+/// // phi(87109) = 79180
+/// assert!(is_totient_permutation(87109));
+/// // phi(10) = 4
+/// assert!(!is_totient_permutation(10));
+/// ```
+pub fn is_totient_permutation(n: u64) -> bool {
+    is_permutation(n, phi(n), 10)
+}
+
 #[cfg_attr(doc, katexit::katexit)]
 /// Congruence relation.
 ///
@@ -923,13 +1337,25 @@ where
     /// # Panics
     /// * If `n` is not positive.
     pub fn new(a: T, n: T) -> Self {
+        Self::try_new(a, n).expect("Modulus must be positive.")
+    }
+
+    /// Create a new [CongruenceRelation].
+    ///
+    /// Same as [CongruenceRelation::new], but returns an error instead of panicking.
+    /// # Arguments
+    /// * `a` - The remainder.
+    /// * `n` - The modulus (must be positive).
+    /// # Errors
+    /// * [PmathError::NonPositiveModulus] - If `n` is not positive.
+    pub fn try_new(a: T, n: T) -> Result<Self, PmathError> {
         if n <= T::ZERO {
-            panic!("Modulus must be positive.");
+            return Err(PmathError::NonPositiveModulus);
         }
-        Self {
+        Ok(Self {
             a: a.rem_euclid(&n),
             n,
-        }
+        })
     }
 
     /// Get the remainder of the congruence relation.
@@ -1030,3 +1456,369 @@ where
 
     Some(a)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_isqrt_errors() {
+        //! Test that [try_isqrt] returns an error for negative integers and matches [isqrt] otherwise
+
+        assert_eq!(try_isqrt(-1i32), Err(PmathError::NegativeArgument));
+        assert_eq!(try_isqrt(i64::MIN), Err(PmathError::NegativeArgument));
+        for n in 0..1000u32 {
+            assert_eq!(try_isqrt(n), Ok(isqrt(n)));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn isqrt_negative() {
+        //! Test that [isqrt] still panics for negative integers
+
+        isqrt(-4i32);
+    }
+
+    #[test]
+    fn try_gcd_errors() {
+        //! Test that [try_gcd] returns an error if either integer is negative
+
+        assert_eq!(try_gcd(-12, 18), Err(PmathError::NegativeArgument));
+        assert_eq!(try_gcd(12, -18), Err(PmathError::NegativeArgument));
+        assert_eq!(try_gcd(-12, -18), Err(PmathError::NegativeArgument));
+        assert_eq!(try_gcd(12, 18), Ok(6));
+        assert_eq!(try_gcd(0, 0), Ok(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn gcd_negative() {
+        //! Test that [gcd] still panics for negative integers
+
+        gcd(-12, 18);
+    }
+
+    #[test]
+    fn congruence_relation_try_new_errors() {
+        //! Test that [CongruenceRelation::try_new] returns an error if the modulus is not positive
+
+        assert_eq!(
+            CongruenceRelation::try_new(3, 0),
+            Err(PmathError::NonPositiveModulus)
+        );
+        assert_eq!(
+            CongruenceRelation::try_new(3, -5),
+            Err(PmathError::NonPositiveModulus)
+        );
+        assert_eq!(
+            CongruenceRelation::try_new(-2, 5),
+            Ok(CongruenceRelation::new(3, 5))
+        );
+    }
+
+    #[test]
+    fn pmath_error_display() {
+        //! Test that every [PmathError] has a message
+
+        for error in [PmathError::NegativeArgument, PmathError::NonPositiveModulus] {
+            assert!(!error.to_string().is_empty());
+        }
+    }
+
+    #[test]
+    fn from_sqrt_integer_matches_from_sqrt() {
+        //! Test that [SimpleContinuedFraction::from_sqrt_integer] matches [SimpleContinuedFraction::from_sqrt]
+
+        for n in 0..2000u64 {
+            let small = SimpleContinuedFraction::from_sqrt(n);
+            let big = SimpleContinuedFraction::from_sqrt_integer(&Integer::from(n));
+            let to_big =
+                |values: &[u64]| values.iter().map(|&v| Integer::from(v)).collect::<Vec<_>>();
+            assert_eq!(big.non_periodic(), to_big(small.non_periodic()));
+            assert_eq!(
+                big.periodic().map(<[_]>::to_vec),
+                small.periodic().map(to_big)
+            );
+        }
+    }
+
+    #[test]
+    fn from_sqrt_integer_large() {
+        //! Test [SimpleContinuedFraction::from_sqrt_integer] on integers beyond the range of [u64]
+
+        // sqrt(k^2 + k) = [k; 2, 2k]
+        let k = Integer::from(10u64.pow(15));
+        let n = &k * &k + &k;
+        let cf = SimpleContinuedFraction::from_sqrt_integer(&n);
+        assert_eq!(cf.non_periodic(), [k.clone()].as_slice());
+        assert_eq!(
+            cf.periodic(),
+            Some([Integer::from(2), &k * Integer::from(2)].as_slice())
+        );
+
+        // the convergent before the end of the period solves the Pell equation x^2 - n * y^2 = 1
+        let convergent = cf.convergents().nth(1).unwrap();
+        let x = Integer::from(convergent.numerator_ref());
+        let y = Integer::from(convergent.denominator_ref());
+        assert_eq!(&x * &x - n * &y * &y, 1);
+
+        // sqrt(k^2 - 1) = [k - 1; 1, 2k - 2], where k^2 overflows u64
+        let k = Integer::from(u64::MAX) + Integer::from(2);
+        let cf = SimpleContinuedFraction::from_sqrt_integer(&(&k * &k - Integer::from(1)));
+        assert_eq!(cf.non_periodic(), [&k - Integer::from(1)].as_slice());
+        assert_eq!(
+            cf.periodic(),
+            Some([Integer::from(1), (&k - Integer::from(1)) * Integer::from(2)].as_slice())
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_sqrt_integer_negative() {
+        //! Test that [SimpleContinuedFraction::from_sqrt_integer] panics for negative integers
+
+        SimpleContinuedFraction::from_sqrt_integer(&Integer::from(-2));
+    }
+
+    #[test]
+    fn pell_fundamental_known() {
+        //! Test [pell_fundamental] against known fundamental solutions
+
+        let known: [(u64, u64, u64); 8] = [
+            (2, 3, 2),
+            (3, 2, 1),
+            (5, 9, 4),
+            (7, 8, 3),
+            (13, 649, 180),
+            (29, 9801, 1820),
+            (61, 1_766_319_049, 226_153_980),
+            (109, 158_070_671_986_249, 15_140_424_455_100),
+        ];
+        for (d, x, y) in known {
+            assert_eq!(
+                pell_fundamental(d),
+                Some((Integer::from(x), Integer::from(y))),
+                "wrong fundamental solution for d = {d}"
+            );
+        }
+    }
+
+    #[test]
+    fn pell_fundamental_solves_equation() {
+        //! Test that [pell_fundamental] solves the equation, or returns [None] for perfect squares
+
+        for d in 0..1000u64 {
+            match pell_fundamental(d) {
+                Some((x, y)) => assert_eq!(&x * &x - Integer::from(d) * &y * &y, 1),
+                None => assert_eq!(isqrt(d) * isqrt(d), d),
+            }
+        }
+    }
+
+    #[test]
+    fn pell_fundamental_large() {
+        //! Test [pell_fundamental] with `d` above `2^53`, where [f64] loses precision
+
+        // the solutions for d = k^2 - 1, k^2 + 1 and k^2 + 2 are known in closed form
+        for k in [(1u64 << 27) + 1, 4_294_967_295] {
+            let big_k = Integer::from(k);
+            assert_eq!(
+                pell_fundamental(k * k - 1),
+                Some((big_k.clone(), Integer::from(1)))
+            );
+            assert_eq!(
+                pell_fundamental(k * k + 1),
+                Some((
+                    Integer::from(2) * &big_k * &big_k + Integer::from(1),
+                    Integer::from(2) * &big_k
+                ))
+            );
+            assert_eq!(
+                pell_fundamental(k * k + 2),
+                Some((&big_k * &big_k + Integer::from(1), big_k))
+            );
+        }
+        assert_eq!(
+            pell_fundamental(u64::MAX),
+            Some((Integer::from(1u64 << 32), Integer::from(1)))
+        );
+        assert_eq!(pell_fundamental(4_294_967_295 * 4_294_967_295), None);
+    }
+
+    #[test]
+    fn best_rational_approximation_pi() {
+        //! Test [best_rational_approximation] on the approximations of pi
+
+        use std::f64::consts::PI;
+
+        let expected = [
+            (1, (3, 1)),
+            (7, (22, 7)),
+            (10, (22, 7)),
+            (57, (179, 57)),
+            (100, (311, 99)),
+            (113, (355, 113)),
+            (1000, (355, 113)),
+            (30_000, (94_053, 29_938)),
+        ];
+        for (max_denominator, fraction) in expected {
+            assert_eq!(best_rational_approximation(PI, max_denominator), fraction);
+        }
+        assert_eq!(best_rational_approximation(-PI, 7), (-22, 7));
+    }
+
+    #[test]
+    fn best_rational_approximation_exact() {
+        //! Test [best_rational_approximation] on numbers representable within the bound
+
+        assert_eq!(best_rational_approximation(0.5, 10), (1, 2));
+        assert_eq!(best_rational_approximation(0.1, 1000), (1, 10));
+        assert_eq!(best_rational_approximation(-2.0, 1), (-2, 1));
+        assert_eq!(best_rational_approximation(0.0, 5), (0, 1));
+        assert_eq!(
+            best_rational_approximation(std::f64::consts::E, 1000),
+            (1457, 536)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn best_rational_approximation_not_finite() {
+        //! Test that [best_rational_approximation] panics for non-finite numbers
+
+        best_rational_approximation(f64::NAN, 10);
+    }
+
+    #[test]
+    fn totient_ratio_values() {
+        //! Test [totient_ratio] on primes, prime powers and products of small primes
+
+        assert_eq!(totient_ratio(1), 1.0);
+        assert_eq!(totient_ratio(6), 3.0);
+        assert_eq!(totient_ratio(8), 2.0);
+        assert_eq!(totient_ratio(7), 7.0 / 6.0);
+        assert_eq!(totient_ratio(30), 30.0 / 8.0);
+        // no integer below 1000 has a larger ratio than 2 * 3 * 5 * 7 = 210
+        assert!((1..1000).all(|n| totient_ratio(n) <= totient_ratio(210)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn totient_ratio_zero() {
+        //! Test that [totient_ratio] panics for 0
+
+        totient_ratio(0);
+    }
+
+    #[test]
+    fn is_totient_permutation_values() {
+        //! Test [is_totient_permutation] on integers with and without the property
+
+        assert!(is_totient_permutation(87109));
+        assert!(is_totient_permutation(21));
+        assert!(is_totient_permutation(8_319_823));
+        assert!(!is_totient_permutation(10));
+        assert!(!is_totient_permutation(87110));
+    }
+
+    #[test]
+    fn e_continued_fraction_coefficients() {
+        //! Test the first coefficients and the convergents of [e_continued_fraction]
+
+        assert_eq!(
+            e_continued_fraction(0).non_periodic(),
+            Vec::<u64>::new().as_slice()
+        );
+        assert_eq!(
+            e_continued_fraction(14).non_periodic(),
+            vec![2, 1, 2, 1, 1, 4, 1, 1, 6, 1, 1, 8, 1, 1].as_slice()
+        );
+
+        let numerators = e_continued_fraction(10)
+            .convergents()
+            .map(|convergent| convergent.into_numerator())
+            .collect::<Vec<_>>();
+        let expected = [2u32, 3, 8, 11, 19, 87, 106, 193, 1264, 1457];
+        assert_eq!(numerators, expected.map(Natural::from));
+    }
+
+    #[test]
+    fn mod_pow_naive() {
+        //! Test [mod_pow] against repeated multiplication for small inputs
+
+        for modulus in 1..20u64 {
+            for base in 0..20 {
+                let mut expected = 1 % modulus;
+                for exp in 0..20 {
+                    assert_eq!(
+                        mod_pow(base, exp, modulus),
+                        expected,
+                        "mod_pow({base}, {exp}, {modulus})"
+                    );
+                    expected = expected * base % modulus;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn mod_pow_large() {
+        //! Test [mod_pow] with negative bases and values whose products overflow the type
+
+        assert_eq!(mod_pow(2, 90, 97), 47);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+        assert_eq!(mod_pow(-2i32, 3, 5), 2);
+        assert_eq!(mod_pow(3u64, 10u64.pow(18), 1_000_000_007), 246_336_683);
+        assert_eq!(
+            mod_pow(
+                9_223_372_036_854_775_813u64,
+                4_611_686_018_427_387_907,
+                18_446_744_073_709_551_557
+            ),
+            8_752_701_111_785_013_372
+        );
+        assert_eq!(mod_pow(i64::MAX, i64::MAX, i64::MAX - 1), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mod_pow_zero_modulus() {
+        //! Test that [mod_pow] panics for a zero modulus
+
+        mod_pow(2, 3, 0);
+    }
+
+    #[test]
+    fn mod_inverse_general() {
+        //! Test [mod_inverse] on integers larger than the modulus, non-coprime integers and `1`
+
+        assert_eq!(mod_inverse(1, 7), Some(1));
+        assert_eq!(mod_inverse(1, 1), Some(0));
+        assert_eq!(mod_inverse(10, 7), Some(5));
+        assert_eq!(mod_inverse(-4, 7), Some(5));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(0, 5), None);
+        assert_eq!(mod_inverse(14, 7), None);
+
+        for n in 1..100i64 {
+            for a in 0..200 {
+                match mod_inverse(a, n) {
+                    Some(x) => {
+                        assert!((0..n).contains(&x));
+                        assert_eq!(a * x % n, 1 % n);
+                    }
+                    None => assert_ne!(gcd(a, n), 1),
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn mod_inverse_zero_modulus() {
+        //! Test that [mod_inverse] panics for a zero modulus
+
+        mod_inverse(3, 0);
+    }
+}
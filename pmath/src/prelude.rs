@@ -0,0 +1,32 @@
+//! Commonly used items of this crate.
+//!
+//! Importing the prelude brings the most frequently needed functions and types into scope.
+//! # Example
+//! ```
+//! use pmath::prelude::*;
+//!
+//! assert_eq!(gcd(48, 18), 6);
+//! assert_eq!(lcm(4, 6), 12);
+//! assert_eq!(isqrt(99), 9);
+//! assert_eq!(factorial(5), 120);
+//! assert_eq!(phi(36), 12);
+//! assert!(is_prime(29).0);
+//! assert_eq!(sieve_of_eratosthenes(10), vec![2, 3, 5, 7]);
+//! assert_eq!(prime_factors(12).collect::<Vec<_>>(), vec![2, 2, 3]);
+//! assert!(is_palindrome(12321, 10));
+//! assert_eq!(digits(123u32, 10).sum::<u32>(), 6);
+//!
+//! let cf = SimpleContinuedFraction::from_sqrt(2);
+//! assert_eq!(cf.periodic(), Some(vec![2].as_slice()));
+//! ```
+
+pub use crate::digits::{
+    digit_permutations, digit_rotations, digits, digits_to_int, is_palindrome, is_permutation,
+    reverse,
+};
+pub use crate::factors::{distinct_prime_factors, divisors, prime_factors, proper_divisors};
+pub use crate::primes::{
+    coprime, is_prime, is_prime_mr, primes, primes_up_to, sieve_of_eratosthenes,
+};
+pub use crate::sequences::{CollatzSeq, FibonacciSeq, Sequence};
+pub use crate::{SimpleContinuedFraction, factorial, gcd, gcd_extended, isqrt, lcm, ord, phi};
@@ -1,7 +1,9 @@
 //! Functions related to prime numbers.
 
-use crate::{gcd, newtons_method};
+use crate::{gcd, isqrt, mod_pow, newtons_method};
 use num_traits::{ConstOne, ConstZero, PrimInt, ToPrimitive};
+use std::collections::HashMap;
+use std::iter;
 
 #[cfg_attr(doc, katexit::katexit)]
 /// A prime-counting function.
@@ -92,6 +94,32 @@ where
     }
 }
 
+#[cfg_attr(doc, katexit::katexit)]
+/// The prime-counting function $\pi(x)$.
+///
+/// Counts the primes less than or equal to $x$ exactly,
+/// unlike [pcf] which only estimates it.
+/// The primes are found with [sieve_of_eratosthenes].
+/// # Arguments
+/// * `x` - The number to count the primes less than or equal to.
+/// # Returns
+/// * The number of primes less than or equal to `x`.
+/// # Panics
+/// * If the sieve requires more elements than can be represented by [usize].
+/// # Example
+/// ```
+/// use pmath::primes::prime_pi;
+///
+/// assert_eq!(prime_pi(10), 4); // 2, 3, 5, 7
+/// assert_eq!(prime_pi(100), 25);
+/// ```
+pub fn prime_pi<T>(x: T) -> usize
+where
+    T: PrimInt + ConstOne,
+{
+    sieve_of_eratosthenes(x).len()
+}
+
 /// Check if two integers are coprime.
 /// # Arguments
 /// * `a` - The first integer.
@@ -172,6 +200,68 @@ where
     }
 }
 
+/// Check if an integer is prime using the Miller-Rabin test.
+///
+/// The test is deterministic for all integers that fit in [u64],
+/// since the first 12 primes as witnesses are known to be sufficient for them.
+/// Much faster than [is_prime] for large integers.
+/// # Arguments
+/// * `n` - The integer to check the primality of.
+/// # Returns
+/// * Whether the integer is prime. Integers less than `2` are not prime.
+/// # Panics
+/// * If `n` is greater than [u64::MAX].
+/// # Example
+/// ```
+/// use pmath::primes::is_prime_mr;
+///
+/// assert!(is_prime_mr(7));
+/// assert!(!is_prime_mr(12));
+/// assert!(!is_prime_mr(1));
+/// // the largest prime that fits in u64
+/// assert!(is_prime_mr(18_446_744_073_709_551_557u64));
+/// ```
+pub fn is_prime_mr<T>(n: T) -> bool
+where
+    T: PrimInt,
+{
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < T::from(2).unwrap() {
+        return false;
+    }
+    let n = n.to_u64().expect("n must not be greater than u64::MAX.");
+
+    // small primes and integers with small factors
+    for witness in WITNESSES {
+        if n == witness {
+            return true;
+        } else if n.is_multiple_of(witness) {
+            return false;
+        }
+    }
+
+    // n - 1 = d * 2^s, where d is odd
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    // n is prime if no witness proves it composite
+    'witnesses: for witness in WITNESSES {
+        let mut x = mod_pow(witness, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = (u128::from(x) * u128::from(x) % u128::from(n)) as u64;
+            if x == n - 1 {
+                continue 'witnesses;
+            }
+        }
+        return false;
+    }
+    true
+}
+
 /// The sieve of Eratosthenes.
 ///
 /// Finds all primes less than or equal to `n`.
@@ -242,6 +332,250 @@ where
     }
 }
 
+/// The residues modulo `30` of integers coprime to `2`, `3` and `5`.
+///
+/// All primes greater than `5` have one of these residues.
+const WHEEL: [u64; 8] = [1, 7, 11, 13, 17, 19, 23, 29];
+
+/// The smallest prime greater than an integer.
+///
+/// Candidates are checked with [is_prime_mr], skipping the multiples of `2`, `3` and `5`.
+/// # Arguments
+/// * `n` - The integer to find the next prime after.
+/// # Returns
+/// * The smallest prime strictly greater than `n`.
+/// # Panics
+/// * If `n` is greater than [u64::MAX].
+/// * If the next prime does not fit in the type `T` or in [u64].
+/// # Example
+/// ```
+/// use pmath::primes::next_prime;
+///
+/// assert_eq!(next_prime(13), 17);
+/// assert_eq!(next_prime(14), 17);
+/// assert_eq!(next_prime(-3), 2);
+/// ```
+pub fn next_prime<T>(n: T) -> T
+where
+    T: PrimInt,
+{
+    if n < T::from(2).unwrap() {
+        return T::from(2).unwrap();
+    }
+    let n = n.to_u64().expect("n must not be greater than u64::MAX.");
+
+    let next = if n < 5 {
+        [3, 5].into_iter().find(|&prime| prime > n).unwrap()
+    } else {
+        let start = n
+            .checked_add(1)
+            .expect("The next prime does not fit in u64.");
+        let mut base = start - start % 30;
+        let mut index = WHEEL
+            .iter()
+            .position(|&residue| residue >= start % 30)
+            .unwrap();
+        loop {
+            let candidate = base
+                .checked_add(WHEEL[index])
+                .expect("The next prime does not fit in u64.");
+            if is_prime_mr(candidate) {
+                break candidate;
+            }
+            index += 1;
+            if index == WHEEL.len() {
+                index = 0;
+                base += 30;
+            }
+        }
+    };
+    T::from(next).expect("The next prime does not fit in the type T.")
+}
+
+/// The largest prime less than an integer.
+///
+/// Candidates are checked with [is_prime_mr], skipping the multiples of `2`, `3` and `5`.
+/// # Arguments
+/// * `n` - The integer to find the previous prime before.
+/// # Returns
+/// * The largest prime strictly less than `n`, or [None] if `n` is less than or equal to `2`.
+/// # Panics
+/// * If `n` is greater than [u64::MAX].
+/// # Example
+/// ```
+/// use pmath::primes::prev_prime;
+///
+/// assert_eq!(prev_prime(17), Some(13));
+/// assert_eq!(prev_prime(16), Some(13));
+/// assert_eq!(prev_prime(2), None);
+/// ```
+pub fn prev_prime<T>(n: T) -> Option<T>
+where
+    T: PrimInt,
+{
+    if n <= T::from(2).unwrap() {
+        return None;
+    }
+    let n = n.to_u64().expect("n must not be greater than u64::MAX.");
+
+    let prev = if n <= 7 {
+        [5, 3, 2].into_iter().find(|&prime| prime < n).unwrap()
+    } else {
+        // 7 is on the wheel, so the search always ends before going below it
+        let end = n - 1;
+        let mut base = end - end % 30;
+        let mut index = match WHEEL.iter().rposition(|&residue| residue <= end % 30) {
+            Some(index) => index,
+            None => {
+                base -= 30;
+                WHEEL.len() - 1
+            }
+        };
+        loop {
+            let candidate = base + WHEEL[index];
+            if is_prime_mr(candidate) {
+                break candidate;
+            }
+            if index == 0 {
+                index = WHEEL.len();
+                base -= 30;
+            }
+            index -= 1;
+        }
+    };
+    // the previous prime is smaller than n, so it fits in the type T
+    T::from(prev)
+}
+
+/// An infinite iterator over the primes.
+///
+/// Uses an incremental sieve of Eratosthenes, which stores the next odd multiple
+/// of each found prime in a hash map, so no upper bound is needed upfront.
+/// The primes are yielded in ascending order.
+/// # Returns
+/// * An iterator over all primes.
+/// # Example
+/// ```
+/// use pmath::primes::primes;
+///
+/// assert_eq!(primes().take(5).collect::<Vec<_>>(), vec![2, 3, 5, 7, 11]);
+/// // the 10001st prime
+/// assert_eq!(primes().nth(10_000), Some(104_743));
+/// ```
+pub fn primes() -> impl Iterator<Item = u64> {
+    // maps the upcoming odd composites to the step to their next odd multiple (twice the prime)
+    let mut composites = HashMap::<u64, u64>::new();
+    let mut candidate: u64 = 1;
+
+    iter::once(2).chain(iter::from_fn(move || {
+        loop {
+            candidate += 2;
+            match composites.remove(&candidate) {
+                Some(step) => {
+                    // move the prime to its next multiple which is not marked by another prime
+                    let mut multiple = candidate + step;
+                    while composites.contains_key(&multiple) {
+                        multiple += step;
+                    }
+                    composites.insert(multiple, step);
+                }
+                None => {
+                    // smaller multiples of the prime are marked by smaller primes
+                    if let Some(square) = candidate.checked_mul(candidate) {
+                        composites.insert(square, 2 * candidate);
+                    }
+                    return Some(candidate);
+                }
+            }
+        }
+    }))
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// A lazily evaluated sieve of Eratosthenes.
+///
+/// Yields all primes less than or equal to `n` in ascending order.
+/// The range is sieved in segments only as the iterator advances,
+/// so stopping early (e.g. with [Iterator::find] or [Iterator::take_while])
+/// skips the work for the rest of the range.
+/// Only the primes up to $\sqrt{n}$ are found upfront.
+/// # Arguments
+/// * `n` - The number to find all primes less than or equal to.
+/// # Returns
+/// * An iterator over all primes less than or equal to `n`.
+/// # Example
+/// ```
+/// use pmath::primes::primes_up_to;
+///
+/// assert_eq!(primes_up_to(10).collect::<Vec<_>>(), vec![2, 3, 5, 7]);
+/// // only the beginning of the range is sieved
+/// assert_eq!(primes_up_to(1_000_000_000).find(|&p| p > 100), Some(101));
+/// ```
+pub fn primes_up_to(n: u64) -> impl Iterator<Item = u64> {
+    const SEGMENT_SIZE: u64 = 1 << 15;
+
+    // primes used to mark the composites in the segments
+    let base_primes = sieve_of_eratosthenes(isqrt(n));
+
+    (0..=n).step_by(SEGMENT_SIZE as usize).flat_map(move |low| {
+        let high = low.saturating_add(SEGMENT_SIZE - 1).min(n);
+        sieve_segment(low, high, &base_primes)
+    })
+}
+
+/// All primes in a range, found with a segmented sieve of Eratosthenes.
+///
+/// Only the primes up to $\sqrt{hi}$ and the range itself are sieved,
+/// so a narrow range of large integers is cheap.
+/// # Arguments
+/// * `lo` - The lower bound of the range (inclusive).
+/// * `hi` - The upper bound of the range (inclusive).
+/// # Returns
+/// * All primes `p` such that `lo <= p <= hi` in ascending order,
+///   or an empty vector if `lo > hi`.
+/// # Panics
+/// * If the range has more elements than can be represented by [usize].
+/// # Example
+/// ```
+/// use pmath::primes::primes_in_range;
+///
+/// assert_eq!(primes_in_range(10, 30), vec![11, 13, 17, 19, 23, 29]);
+/// assert_eq!(primes_in_range(1_000_000_000, 1_000_000_010), vec![1_000_000_007, 1_000_000_009]);
+/// ```
+pub fn primes_in_range(lo: u64, hi: u64) -> Vec<u64> {
+    if lo > hi {
+        return Vec::new();
+    }
+    sieve_segment(lo, hi, &sieve_of_eratosthenes(isqrt(hi)))
+}
+
+/// The primes in the range `low..=high`, found by marking the multiples of the base primes.
+///
+/// The base primes must include all primes up to the square root of `high`.
+fn sieve_segment(low: u64, high: u64, base_primes: &[u64]) -> Vec<u64> {
+    // segment[i] tells whether low + i is prime
+    let length = usize::try_from(high - low).expect("Range too large.") + 1;
+    let mut segment = vec![true; length];
+    // 0 and 1 are not prime
+    for value in low..=high.min(1) {
+        segment[(value - low) as usize] = false;
+    }
+
+    for &prime in base_primes.iter().take_while(|&&p| p * p <= high) {
+        // start at prime^2 (all smaller multiples have already been marked by smaller primes)
+        let first = (prime * prime).max(low.div_ceil(prime).saturating_mul(prime));
+        for multiple in (first..=high).step_by(prime as usize) {
+            segment[(multiple - low) as usize] = false;
+        }
+    }
+
+    segment
+        .into_iter()
+        .zip(low..=high)
+        .filter_map(|(prime, value)| prime.then_some(value))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -600,4 +934,193 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn primes_up_to_matches_sieve() {
+        //! Test that [primes_up_to] yields the same primes as [sieve_of_eratosthenes]
+
+        for n in (0..=100).chain([32_767, 32_768, 32_769, 65_536, 100_000, 1_000_003]) {
+            assert_eq!(
+                primes_up_to(n).collect::<Vec<_>>(),
+                sieve_of_eratosthenes(n),
+                "primes_up_to({n}) does not match sieve_of_eratosthenes({n})"
+            );
+        }
+    }
+
+    #[test]
+    fn primes_up_to_short_circuit() {
+        //! Test that [primes_up_to] can be stopped early on a range too large to sieve completely
+
+        let n = 1_000_000_000_000;
+        assert_eq!(primes_up_to(n).find(|&p| p > 1000), Some(1009));
+        assert_eq!(primes_up_to(n).take_while(|&p| p < 100).count(), 25);
+        assert_eq!(primes_up_to(n).nth(9999), Some(104_729));
+    }
+
+    #[test]
+    fn is_prime_mr_matches_sieve() {
+        //! Test that [is_prime_mr] agrees with [sieve_of_eratosthenes] below 100000
+
+        let mut primes = sieve_of_eratosthenes(100_000u64).into_iter().peekable();
+        for n in 0..100_000u64 {
+            let expected = primes.next_if_eq(&n).is_some();
+            assert_eq!(
+                is_prime_mr(n),
+                expected,
+                "is_prime_mr({n}) should be {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn is_prime_mr_large() {
+        //! Test [is_prime_mr] on large primes, pseudoprimes and other types
+
+        // strong pseudoprimes to several of the smallest bases
+        for composite in [
+            561u64,
+            3_215_031_751,
+            2_152_302_898_747,
+            3_474_749_660_383,
+            341_550_071_728_321,
+            3_825_123_056_546_413_051,
+            // 4294967291^2
+            18_446_744_030_759_878_681,
+        ] {
+            assert!(!is_prime_mr(composite), "{composite} is composite");
+        }
+        for prime in [
+            1_000_000_007u64,
+            2_305_843_009_213_693_951,
+            18_446_744_073_709_551_557,
+        ] {
+            assert!(is_prime_mr(prime), "{prime} is prime");
+        }
+
+        assert!(!is_prime_mr(-7i32));
+        assert!(is_prime_mr(127i8));
+        assert!(is_prime_mr(65_521u16));
+        assert!(is_prime_mr(4_294_967_291u128));
+    }
+
+    #[test]
+    fn primes_infinite() {
+        //! Test that [primes] yields the primes in order without an upper bound
+
+        assert_eq!(
+            primes().take(20).collect::<Vec<_>>(),
+            PRIMES_TO_100[..20]
+                .iter()
+                .map(|&p| p as u64)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(primes().nth(10_000), Some(104_743));
+        assert!(
+            primes()
+                .take_while(|&p| p < 1_000_000)
+                .eq(sieve_of_eratosthenes(999_999u64))
+        );
+    }
+
+    #[test]
+    fn next_prime_prev_prime_small() {
+        //! Test [next_prime] and [prev_prime] against the primes below 100
+
+        for n in -5..100 {
+            let next = PRIMES_TO_100.into_iter().find(|&p| p > n).unwrap_or(101);
+            assert_eq!(next_prime(n), next, "next_prime({n})");
+
+            let prev = PRIMES_TO_100.into_iter().rev().find(|&p| p < n);
+            assert_eq!(prev_prime(n), prev, "prev_prime({n})");
+        }
+        assert_eq!(next_prime(13), 17);
+        assert_eq!(next_prime(14), 17);
+        assert_eq!(prev_prime(2), None);
+        assert_eq!(prev_prime(3), Some(2));
+    }
+
+    #[test]
+    fn next_prime_prev_prime_large() {
+        //! Test [next_prime] and [prev_prime] around large primes and the limits of the types
+
+        assert_eq!(next_prime(1_000_000_000u64), 1_000_000_007);
+        assert_eq!(prev_prime(1_000_000_007u64), Some(999_999_937));
+        assert_eq!(prev_prime(u64::MAX), Some(18_446_744_073_709_551_557));
+        assert_eq!(
+            next_prime(18_446_744_073_709_551_556u64),
+            18_446_744_073_709_551_557
+        );
+        assert_eq!(next_prime(120u8), 127);
+        assert_eq!(prev_prime(u8::MAX), Some(251));
+    }
+
+    #[test]
+    #[should_panic]
+    fn next_prime_overflow() {
+        //! Test that [next_prime] panics if the next prime does not fit in the type
+
+        next_prime(127i8);
+    }
+
+    #[test]
+    fn prime_pi_values() {
+        //! Test [prime_pi] for small, negative and large values
+
+        assert_eq!(prime_pi(-10), 0);
+        assert_eq!(prime_pi(0), 0);
+        assert_eq!(prime_pi(1), 0);
+        assert_eq!(prime_pi(2), 1);
+        assert_eq!(prime_pi(10), 4);
+        assert_eq!(prime_pi(100u8), 25);
+        assert_eq!(prime_pi(1_000_000u64), 78_498);
+        // the estimate is exact for x < 11 and an underestimate above
+        for x in 0..11 {
+            assert_eq!(prime_pi(x) as f64, pcf(x));
+        }
+        assert!((prime_pi(1000) as f64) > pcf(1000));
+    }
+
+    #[test]
+    fn primes_in_range_matches_sieve() {
+        //! Test that [primes_in_range] matches filtering [sieve_of_eratosthenes]
+
+        let primes = sieve_of_eratosthenes(1_001_000u64);
+        let filtered = |lo: u64, hi: u64| {
+            primes
+                .iter()
+                .copied()
+                .filter(|p| (lo..=hi).contains(p))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            primes_in_range(1_000_000, 1_001_000),
+            filtered(1_000_000, 1_001_000)
+        );
+        for lo in 0..30 {
+            for hi in lo..60 {
+                assert_eq!(primes_in_range(lo, hi), filtered(lo, hi), "[{lo}, {hi}]");
+            }
+        }
+    }
+
+    #[test]
+    fn primes_in_range_edge_cases() {
+        //! Test [primes_in_range] for empty, single element and large ranges
+
+        assert_eq!(primes_in_range(10, 5), Vec::<u64>::new());
+        assert_eq!(primes_in_range(7, 7), vec![7]);
+        assert_eq!(primes_in_range(8, 8), Vec::<u64>::new());
+        assert_eq!(primes_in_range(0, 1), Vec::<u64>::new());
+        assert_eq!(primes_in_range(0, 2), vec![2]);
+        assert_eq!(
+            primes_in_range(1_000_000_000_000, 1_000_000_000_100),
+            vec![
+                1_000_000_000_039,
+                1_000_000_000_061,
+                1_000_000_000_063,
+                1_000_000_000_091
+            ]
+        );
+    }
 }
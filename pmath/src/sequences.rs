@@ -1,6 +1,7 @@
 //! Iterators over mathematical sequences.
 
 use num_traits::{ConstOne, ConstZero, NumCast, PrimInt};
+use std::iter;
 
 /// A trait for mathematical sequences that can be iterated over.
 pub trait Sequence<T>: Iterator<Item = T>
@@ -100,6 +101,169 @@ where
 }
 impl<T> Sequence<T> for CollatzSeq<T> where T: PrimInt + ConstZero + ConstOne {}
 
+/// A memoized calculator of the Collatz sequence lengths.
+///
+/// The length of a sequence is the number of its terms, same as
+/// the number of elements yielded by [CollatzSeq].
+/// Lengths are stored for starting points below the limit,
+/// so a sequence is only followed until it reaches an already known one.
+/// # Example
+/// ```
+/// use pmath::sequences::CollatzCache;
+///
+/// let mut cache = CollatzCache::new(100);
+/// // 13, 40, 20, 10, 5, 16, 8, 4, 2, 1
+/// assert_eq!(cache.length(13), 10);
+/// // 26 continues with the sequence of 13
+/// assert_eq!(cache.length(26), 11);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CollatzCache {
+    lengths: Vec<u32>,
+    path: Vec<u64>,
+}
+impl CollatzCache {
+    /// Create a new cache for the Collatz sequence lengths.
+    /// # Arguments
+    /// * `limit` - The starting points below this limit have their lengths stored.
+    /// # Returns
+    /// * The empty cache.
+    pub fn new(limit: usize) -> Self {
+        Self {
+            lengths: vec![0; limit],
+            path: Vec::new(),
+        }
+    }
+
+    /// The length of the Collatz sequence starting at the integer `n`.
+    ///
+    /// The lengths of all terms of the sequence below the limit are stored.
+    /// # Arguments
+    /// * `n` - The integer to start the Collatz sequence at.
+    /// # Returns
+    /// * The number of terms in the sequence, including `n` and the final `1`.
+    /// # Panics
+    /// * If `n` < `1` since the Collatz sequence requires a positive integer starting point.
+    pub fn length(&mut self, n: u64) -> u32 {
+        if n < 1 {
+            panic!("Collatz sequence requires a positive integer starting point.");
+        }
+
+        // follow the sequence until a term with a known length
+        let mut current = n;
+        let mut length = loop {
+            if current == 1 {
+                break 1;
+            }
+            match self.index(current).map(|index| self.lengths[index]) {
+                Some(length) if length != 0 => break length,
+                _ => {}
+            }
+            self.path.push(current);
+            current = if current.is_multiple_of(2) {
+                current / 2
+            } else {
+                3 * current + 1
+            };
+        };
+
+        // each term on the path is one longer than the term after it
+        while let Some(term) = self.path.pop() {
+            length += 1;
+            if let Some(index) = self.index(term) {
+                self.lengths[index] = length;
+            }
+        }
+        length
+    }
+
+    /// The index of the stored length of `n`, if it is below the limit.
+    fn index(&self, n: u64) -> Option<usize> {
+        usize::try_from(n)
+            .ok()
+            .filter(|&index| index < self.lengths.len())
+    }
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// The Farey sequence of order `n`.
+///
+/// The reduced fractions between $0$ and $1$ whose denominators do not exceed `n`,
+/// in ascending order.
+/// Each fraction is calculated from the previous two, so no sorting is needed:
+/// if $\frac{a}{b}$ and $\frac{c}{d}$ are consecutive,
+/// the next fraction is $\frac{k \cdot c - a}{k \cdot d - b}$,
+/// where $k = \left\lfloor \frac{n + b}{d} \right\rfloor$.
+/// # Arguments
+/// * `n` - The order of the sequence.
+/// # Returns
+/// * An iterator over the fractions as `(numerator, denominator)` pairs,
+///   from `(0, 1)` to `(1, 1)`, or an empty iterator if `n` is `0`.
+/// # Example
+/// ```
+/// use pmath::sequences::farey;
+///
+/// assert_eq!(
+///     farey(3).collect::<Vec<_>>(),
+///     vec![(0, 1), (1, 3), (1, 2), (2, 3), (1, 1)]
+/// );
+/// ```
+pub fn farey(n: u64) -> impl Iterator<Item = (u64, u64)> {
+    // the current fraction and the one after it
+    let mut fractions = (n != 0).then_some(((0, 1), (1, n)));
+    iter::from_fn(move || {
+        let ((a, b), (c, d)) = fractions?;
+        fractions = if (a, b) == (1, 1) {
+            None
+        } else {
+            let k = (n + b) / d;
+            Some(((c, d), (k * c - a, k * d - b)))
+        };
+        Some((a, b))
+    })
+}
+
+/// The numbers on the diagonals of a number spiral.
+///
+/// The spiral starts with `1` in the center and continues clockwise:
+/// ```text
+/// 21 22 23 24 25
+/// 20  7  8  9 10
+/// 19  6  1  2 11
+/// 18  5  4  3 12
+/// 17 16 15 14 13
+/// ```
+/// Each layer around the center adds four corners to the diagonals,
+/// the last of them being the square of the layer's side length.
+/// # Arguments
+/// * `size` - The side length of the spiral.
+/// # Returns
+/// * An iterator over the numbers on the diagonals in ascending order.
+/// # Panics
+/// * If `size` is not odd.
+/// # Example
+/// ```
+/// use pmath::sequences::spiral_diagonals;
+///
+/// assert_eq!(
+///     spiral_diagonals(5).collect::<Vec<_>>(),
+///     vec![1, 3, 5, 7, 9, 13, 17, 21, 25]
+/// );
+/// ```
+pub fn spiral_diagonals(size: u64) -> impl Iterator<Item = u64> {
+    if size.is_multiple_of(2) {
+        panic!("The size of the spiral must be odd.");
+    }
+
+    let layers = (3..=size).step_by(2).flat_map(|side| {
+        // corners are side - 1 apart, going back from the square of the side length
+        (0..4)
+            .rev()
+            .map(move |corner| side * side - corner * (side - 1))
+    });
+    iter::once(1).chain(layers)
+}
+
 #[cfg_attr(doc, katexit::katexit)]
 /// The Fibonacci sequence.
 ///
@@ -1107,6 +1271,37 @@ mod tests {
         );
     }
 
+    #[test]
+    #[should_panic]
+    fn collatz_cache_nonpositive_start() {
+        //! Test that the [CollatzCache::length] panics when given a non-positive starting point.
+
+        CollatzCache::new(10).length(0);
+    }
+
+    #[test]
+    fn collatz_cache_verify() {
+        //! Test that the [CollatzCache] gives the same lengths as [CollatzSeq],
+        //! both for starting points below and above the limit.
+
+        let mut cache = CollatzCache::new(100);
+        for start in 1..1000 {
+            assert_eq!(
+                cache.length(start) as usize,
+                CollatzSeq::new(start).count(),
+                "start = {start}"
+            );
+        }
+        // lengths are the same when taken from the cache
+        for start in (1..1000).rev() {
+            assert_eq!(cache.length(start) as usize, CollatzSeq::new(start).count());
+        }
+        assert_eq!(
+            CollatzCache::new(0).length(6),
+            COLLATZ_SEQ_EXAMPLE.len() as u32
+        );
+    }
+
     const FIBONACCI_SEQ_EXAMPLE: [i32; 40] = [
         0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89, 144, 233, 377, 610, 987, 1597, 2584, 4181, 6765,
         10946, 17711, 28657, 46368, 75025, 121393, 196418, 317811, 514229, 832040, 1346269,
@@ -2264,4 +2459,69 @@ mod tests {
             assert_eq!(EvenNatNumW0SqSeq::<u32>::new().sum_next_n(n), sum as u32);
         }
     }
+
+    #[test]
+    fn farey_order_5() {
+        //! Test that [farey] yields the Farey sequence of order 5 in ascending order
+
+        assert_eq!(
+            farey(5).collect::<Vec<_>>(),
+            vec![
+                (0, 1),
+                (1, 5),
+                (1, 4),
+                (1, 3),
+                (2, 5),
+                (1, 2),
+                (3, 5),
+                (2, 3),
+                (3, 4),
+                (4, 5),
+                (1, 1)
+            ]
+        );
+        assert_eq!(farey(0).count(), 0);
+        assert_eq!(farey(1).collect::<Vec<_>>(), vec![(0, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn farey_length() {
+        //! Test that the Farey sequence of order `n` has `1 + phi(1) + ... + phi(n)` reduced fractions in ascending order
+
+        let phi_values = crate::phi_0_to_n(100u64);
+        for n in 1..=100 {
+            let fractions = farey(n).collect::<Vec<_>>();
+            let expected = 1 + phi_values[..=n as usize].iter().sum::<u64>();
+            assert_eq!(fractions.len() as u64, expected, "wrong length for n = {n}");
+            assert!(
+                fractions
+                    .iter()
+                    .all(|&(a, b)| b <= n && crate::gcd(a, b) == 1)
+            );
+            assert!(
+                fractions
+                    .windows(2)
+                    .all(|w| w[0].0 * w[1].1 < w[1].0 * w[0].1)
+            );
+        }
+    }
+
+    #[test]
+    fn spiral_diagonals_small() {
+        //! Test [spiral_diagonals] on small spirals.
+
+        assert_eq!(spiral_diagonals(1).collect::<Vec<_>>(), vec![1]);
+        assert_eq!(spiral_diagonals(3).collect::<Vec<_>>(), vec![1, 3, 5, 7, 9]);
+        assert_eq!(spiral_diagonals(5).sum::<u64>(), 101);
+        assert_eq!(spiral_diagonals(1001).sum::<u64>(), 669_171_001);
+        assert_eq!(spiral_diagonals(1001).count(), 2001);
+    }
+
+    #[test]
+    #[should_panic]
+    fn spiral_diagonals_even_size() {
+        //! Test that [spiral_diagonals] panics when given an even size.
+
+        let _ = spiral_diagonals(4);
+    }
 }
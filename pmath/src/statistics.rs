@@ -1,6 +1,6 @@
 //! Statistics calculations.
 
-use num_traits::{FromPrimitive, ToPrimitive};
+use num_traits::ToPrimitive;
 use std::borrow::Borrow;
 use std::collections::HashMap;
 use std::ops::Deref;
@@ -19,6 +19,9 @@ use std::ops::DerefMut;
 /// uses _Welford's online algorithm_ for calculating variance and standard deviation.
 /// There are a few methods (similar to methods from [Vec])
 /// to add or remove data points from the sample.
+///
+/// Data points can be of any type that can be converted to [f64] (integers or floats),
+/// and all statistics are calculated and returned as [f64].
 /// # Example
 /// ```
 /// use pmath::statistics::Sample;
@@ -33,7 +36,7 @@ use std::ops::DerefMut;
 ///
 /// assert_eq!(sample.mean().unwrap(), 3.0);
 /// assert_eq!(sample.median().unwrap(), 3.0);
-/// assert_eq!(sample.mode().unwrap(), 3);
+/// assert_eq!(sample.mode().unwrap(), 3.0);
 /// assert_eq!(sample.sample_variance().unwrap(), 4.0 / 3.0);
 /// assert_eq!(sample.sample_stddev().unwrap(), (4.0_f64 / 3.0).sqrt());
 /// assert_eq!(sample.population_variance().unwrap(), 1.2);
@@ -47,7 +50,7 @@ pub struct Sample<T> {
 }
 impl<T> Sample<T>
 where
-    T: Copy + FromPrimitive + ToPrimitive,
+    T: Copy + ToPrimitive,
 {
     /// Create a new empty [Sample].
     /// # Returns
@@ -72,11 +75,7 @@ where
         U: IntoIterator<Item = I>,
         I: Borrow<T>,
     {
-        let mut sample = Self::new();
-        for value in data.into_iter().map(|t| *t.borrow()) {
-            sample.push(value);
-        }
-        sample
+        data.into_iter().map(|t| *t.borrow()).collect()
     }
 
     /// Add a data point to the end of the sample.
@@ -141,6 +140,44 @@ where
         value
     }
 
+    /// Append all data points from another sample to the end of this sample.
+    ///
+    /// Mean and variance are combined using _Chan's parallel algorithm_,
+    /// so this does not need to iterate over the data points again.
+    /// # Arguments
+    /// * `other` - The sample whose data points are to be appended.
+    pub fn merge(&mut self, other: &Sample<T>) {
+        self.data.extend_from_slice(&other.data);
+        match (self.mean.zip(self.m2), other.mean.zip(other.m2)) {
+            (_, None) => {}
+            (None, Some(_)) => {
+                self.mean = other.mean;
+                self.m2 = other.m2;
+            }
+            (Some((mean_a, m2_a)), Some((mean_b, m2_b))) => {
+                let n_b = other.len() as f64;
+                let n_a = self.len() as f64 - n_b;
+                let n = self.len() as f64;
+                let delta = mean_b - mean_a;
+                self.mean = Some(mean_a + delta * n_b / n);
+                self.m2 = Some(m2_a + m2_b + delta * delta * n_a * n_b / n);
+            }
+        }
+    }
+
+    /// Combine this sample with another sample.
+    ///
+    /// This is the consuming version of [Sample::merge].
+    /// # Arguments
+    /// * `other` - The sample whose data points are to be appended.
+    /// # Returns
+    /// * A new [Sample] containing data points of this sample followed by
+    ///   data points of the other sample.
+    pub fn merged(mut self, other: Sample<T>) -> Sample<T> {
+        self.merge(&other);
+        self
+    }
+
     /// Clear all data points from the sample.
     pub fn clear(&mut self) {
         self.data.clear();
@@ -160,6 +197,95 @@ where
         self.mean
     }
 
+    /// Calculate geometric mean of the sample.
+    ///
+    /// Geometric mean of the sample is defined as:
+    /// $$
+    ///     G = \\left( \\prod_{i=1}^{n} x_i \\right)^{\\frac{1}{n}}
+    ///       = \\exp \\left( \\frac{1}{n} \\sum_{i=1}^{n} \\ln x_i \\right)
+    /// $$
+    ///
+    /// It is calculated in log space to avoid overflow of the product.
+    /// # Returns
+    /// * An [Option] containing the geometric mean if the sample is not empty
+    ///   and all data points are positive.
+    ///   If any data point is zero or negative, [None] is returned.
+    /// # Panics
+    /// * If the data points cannot be converted to [f64].
+    pub fn geometric_mean(&self) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut log_sum = 0.0;
+        for value in &self.data {
+            let x = value.to_f64().expect("Cannot convert to f64.");
+            if x.is_nan() || x <= 0.0 {
+                return None;
+            }
+            log_sum += x.ln();
+        }
+        Some((log_sum / self.len() as f64).exp())
+    }
+
+    /// Calculate harmonic mean of the sample.
+    ///
+    /// Harmonic mean of the sample is defined as:
+    /// $$
+    ///     H = \\frac{n}{\\sum_{i=1}^{n} \\frac{1}{x_i}}
+    /// $$
+    /// # Returns
+    /// * An [Option] containing the harmonic mean if the sample is not empty
+    ///   and all data points are positive.
+    ///   If any data point is zero or negative, [None] is returned.
+    /// # Panics
+    /// * If the data points cannot be converted to [f64].
+    pub fn harmonic_mean(&self) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut reciprocal_sum = 0.0;
+        for value in &self.data {
+            let x = value.to_f64().expect("Cannot convert to f64.");
+            if x.is_nan() || x <= 0.0 {
+                return None;
+            }
+            reciprocal_sum += 1.0 / x;
+        }
+        Some(self.len() as f64 / reciprocal_sum)
+    }
+
+    /// Find the minimum of the sample.
+    ///
+    /// [f64::NAN] values are ignored.
+    /// # Returns
+    /// * An [Option] containing the smallest data point if the sample contains
+    ///   at least one data point that is not [f64::NAN].
+    /// # Panics
+    /// * If the data points cannot be converted to [f64].
+    pub fn min(&self) -> Option<f64> {
+        self.data
+            .iter()
+            .map(|value| value.to_f64().expect("Cannot convert to f64."))
+            .filter(|x| !x.is_nan())
+            .reduce(f64::min)
+    }
+
+    /// Find the maximum of the sample.
+    ///
+    /// [f64::NAN] values are ignored.
+    /// # Returns
+    /// * An [Option] containing the largest data point if the sample contains
+    ///   at least one data point that is not [f64::NAN].
+    /// # Panics
+    /// * If the data points cannot be converted to [f64].
+    pub fn max(&self) -> Option<f64> {
+        self.data
+            .iter()
+            .map(|value| value.to_f64().expect("Cannot convert to f64."))
+            .filter(|x| !x.is_nan())
+            .reduce(f64::max)
+    }
+
     /// Calculate median of the sample.
     ///
     /// Median of the sample is defined as:
@@ -175,52 +301,207 @@ where
         if self.is_empty() {
             return None;
         }
-        let mut sorted = Vec::with_capacity(self.len());
-        for &value in &self.data {
-            sorted.push(value.to_f64().expect("Cannot convert to f64."));
-        }
-        sorted.sort_unstable_by(|x, x1| x.partial_cmp(x1).expect("Cannot compare f64 values."));
+        let sorted = self.sorted_f64();
         let mid = sorted.len() / 2;
-        if sorted.len() % 2 == 0 {
+        if sorted.len().is_multiple_of(2) {
             // Even number of elements, average the two middle values
-            let left = sorted[mid - 1].to_f64().expect("Cannot convert to f64.");
-            let right = sorted[mid].to_f64().expect("Cannot convert to f64.");
-            Some((left + right) / 2.0)
+            Some((sorted[mid - 1] + sorted[mid]) / 2.0)
         } else {
             // Odd number of elements, return the middle value
-            Some(sorted[mid].to_f64().expect("Cannot convert to f64."))
+            Some(sorted[mid])
         }
     }
 
-    /// Calculate the mode of the sample.
+    /// Calculate the quantile of the sample.
     ///
-    /// Mode of the sample is defined as the value that appears most frequently.
-    /// If there are multiple values with the same highest frequency,
-    /// the last one in the sample is returned.
+    /// The quantile is calculated using linear interpolation between the closest
+    /// order statistics (the method used by default in _R_ and _NumPy_).
+    /// For the sorted data points $x_1 \\le x_2 \\le \\ldots \\le x_n$,
+    /// let $h = (n - 1) q$. Then the quantile is defined as:
+    /// $$
+    ///     Q(q) = x_{\\lfloor h \\rfloor + 1} + (h - \\lfloor h \\rfloor) (x_{\\lfloor h \\rfloor + 2} - x_{\\lfloor h \\rfloor + 1})
+    /// $$
+    /// # Arguments
+    /// * `q` - The quantile to calculate, in the range $[0, 1]$ (e.g. `0.95` for the 95th percentile).
     /// # Returns
-    /// * An [Option] containing the mode if the sample is not empty.
+    /// * An [Option] containing the quantile if the sample is not empty.
     /// # Panics
+    /// * If `q` is not in the range $[0, 1]$.
     /// * If the data points cannot be converted to [f64].
-    pub fn mode(&self) -> Option<T> {
+    /// * If the data points cannot be compared as [f64] values. For example, if the data points
+    ///   contain [f64::NAN] values.
+    /// # Example
+    /// ```
+    /// use pmath::statistics::Sample;
+    ///
+    /// let sample = Sample::from_values([1, 2, 3, 4]);
+    /// assert_eq!(sample.quantile(0.0), Some(1.0));
+    /// assert_eq!(sample.quantile(0.5), Some(2.5));
+    /// assert_eq!(sample.quantile(0.75), Some(3.25));
+    /// assert_eq!(sample.quantile(1.0), Some(4.0));
+    /// ```
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        if !(0.0..=1.0).contains(&q) {
+            panic!("q must be in the range [0, 1].");
+        }
         if self.is_empty() {
             return None;
         }
+        let sorted = self.sorted_f64();
+        let h = (sorted.len() - 1) as f64 * q;
+        let lower = h.floor() as usize;
+        let upper = h.ceil() as usize;
+        Some(sorted[lower] + (h - lower as f64) * (sorted[upper] - sorted[lower]))
+    }
+
+    /// Calculate the interquartile range of the sample.
+    ///
+    /// Interquartile range is defined as:
+    /// $$
+    ///     IQR = Q_3 - Q_1
+    /// $$
+    /// where $Q_1$ and $Q_3$ are the first and third quartiles (see [Sample::quantile]).
+    /// # Returns
+    /// * An [Option] containing the interquartile range if the sample is not empty.
+    /// # Panics
+    /// * If the data points cannot be converted to [f64].
+    /// * If the data points cannot be compared as [f64] values. For example, if the data points
+    ///   contain [f64::NAN] values.
+    pub fn iqr(&self) -> Option<f64> {
+        Some(self.quantile(0.75)? - self.quantile(0.25)?)
+    }
+
+    /// Find the outliers of the sample.
+    ///
+    /// Data point $x$ is an outlier if it lies outside the range
+    /// $[Q_1 - k \\cdot IQR, Q_3 + k \\cdot IQR]$ (see [Sample::iqr]).
+    /// The usual choice is $k = 1.5$ for outliers and $k = 3$ for extreme outliers.
+    /// # Arguments
+    /// * `k` - The multiple of the interquartile range.
+    /// # Returns
+    /// * The outliers in the order in which they appear in the sample.
+    /// # Panics
+    /// * If `k` is negative.
+    /// * If the data points cannot be converted to [f64].
+    /// * If the data points cannot be compared as [f64] values. For example, if the data points
+    ///   contain [f64::NAN] values.
+    /// # Example
+    /// ```
+    /// use pmath::statistics::Sample;
+    ///
+    /// let sample = Sample::from_values([10, 12, 11, 13, 12, 95, 11]);
+    /// assert_eq!(sample.outliers(1.5), vec![95.0]);
+    /// ```
+    pub fn outliers(&self, k: f64) -> Vec<f64> {
+        if k < 0.0 {
+            panic!("k must be non-negative.");
+        }
+        let (Some(q1), Some(q3)) = (self.quantile(0.25), self.quantile(0.75)) else {
+            return Vec::new();
+        };
+        let iqr = q3 - q1;
+        let (lower, upper) = (q1 - k * iqr, q3 + k * iqr);
+        self.data
+            .iter()
+            .map(|value| value.to_f64().expect("Cannot convert to f64."))
+            .filter(|&x| x < lower || x > upper)
+            .collect()
+    }
+
+    /// Count the data points in equal-width bins.
+    ///
+    /// The range from the minimum to the maximum of the sample is split into `bins`
+    /// equal-width bins. Every bin includes its start, and the last bin also includes its end
+    /// (the maximum). If all data points are equal, a single degenerate bin is returned.
+    /// [f64::NAN] values are ignored.
+    /// # Arguments
+    /// * `bins` - The number of bins.
+    /// # Returns
+    /// * The `(bin_start, bin_end, count)` triples in ascending order,
+    ///   or an empty [Vec] if the sample has no data points that are not [f64::NAN].
+    /// # Panics
+    /// * If `bins` is `0`.
+    /// * If the data points cannot be converted to [f64].
+    /// # Example
+    /// ```
+    /// use pmath::statistics::Sample;
+    ///
+    /// let sample = Sample::from_values([1, 2, 2, 3, 5]);
+    /// assert_eq!(
+    ///     sample.histogram(2),
+    ///     vec![(1.0, 3.0, 3), (3.0, 5.0, 2)]
+    /// );
+    /// ```
+    pub fn histogram(&self, bins: usize) -> Vec<(f64, f64, usize)> {
+        if bins == 0 {
+            panic!("Number of bins must be greater than 0.");
+        }
+        let (Some(min), Some(max)) = (self.min(), self.max()) else {
+            return Vec::new();
+        };
+        let values = self
+            .data
+            .iter()
+            .map(|value| value.to_f64().expect("Cannot convert to f64."))
+            .filter(|x| !x.is_nan());
+
+        if min == max {
+            return vec![(min, max, values.count())];
+        }
+
+        let width = (max - min) / bins as f64;
+        let mut counts = vec![0; bins];
+        for x in values {
+            let bin = (((x - min) / width) as usize).min(bins - 1);
+            counts[bin] += 1;
+        }
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| {
+                let start = min + width * i as f64;
+                let end = if i == bins - 1 {
+                    max
+                } else {
+                    min + width * (i + 1) as f64
+                };
+                (start, end, count)
+            })
+            .collect()
+    }
+
+    /// Calculate the mode of the sample.
+    ///
+    /// Mode of the sample is defined as the value that appears most frequently.
+    /// Values are grouped by exact equality of their [f64] representations,
+    /// so this is mostly useful for integer-valued samples.
+    /// If there are multiple values with the same highest frequency,
+    /// the smallest one is returned. [f64::NAN] values are ignored.
+    /// # Returns
+    /// * An [Option] containing the mode if the sample contains
+    ///   at least one data point that is not [f64::NAN].
+    /// # Panics
+    /// * If the data points cannot be converted to [f64].
+    pub fn mode(&self) -> Option<f64> {
         let mut occurrences = HashMap::new();
         for &value in &self.data {
-            *occurrences
-                .entry(value.to_f64().expect("Cannot convert to f64.").to_bits())
-                .or_insert(0) += 1usize;
-        }
-        let highest_frequency = *occurrences.values().max().unwrap();
-        for v in self.iter().rev() {
-            if let Some(&count) =
-                occurrences.get(&v.to_f64().expect("Cannot convert to f64.").to_bits())
-                && count == highest_frequency
-            {
-                return Some(*v);
+            let x = value.to_f64().expect("Cannot convert to f64.");
+            if !x.is_nan() {
+                // adding 0.0 turns -0.0 into 0.0, so they are grouped together
+                *occurrences.entry((x + 0.0).to_bits()).or_insert(0) += 1usize;
             }
         }
-        unreachable!("One of the values must be the mode.");
+        occurrences
+            .into_iter()
+            .map(|(bits, count)| (f64::from_bits(bits), count))
+            .reduce(|(mode, mode_count), (x, count)| {
+                if count > mode_count || (count == mode_count && x < mode) {
+                    (x, count)
+                } else {
+                    (mode, mode_count)
+                }
+            })
+            .map(|(mode, _)| mode)
     }
 
     /// Calculate variance of the sample.
@@ -239,6 +520,16 @@ where
         }
     }
 
+    /// Calculate variance of the sample.
+    ///
+    /// This is the same as [Sample::sample_variance] and is provided for convenience,
+    /// since the sample variance is the one most commonly meant by "variance".
+    /// # Returns
+    /// * An [Option] containing the sample variance if the sample has at least 2 points.
+    pub fn variance(&self) -> Option<f64> {
+        self.sample_variance()
+    }
+
     /// Calculate standard deviation of the sample.
     ///
     /// Standard deviation of the sample from a population is defined as:
@@ -251,6 +542,16 @@ where
         self.sample_variance().map(|v| v.sqrt())
     }
 
+    /// Calculate standard deviation of the sample.
+    ///
+    /// This is the same as [Sample::sample_stddev] and is provided for convenience,
+    /// matching [Sample::variance].
+    /// # Returns
+    /// * An [Option] containing the sample standard deviation if the sample has at least 2 points.
+    pub fn stddev(&self) -> Option<f64> {
+        self.sample_stddev()
+    }
+
     /// Calculate variance of the population.
     ///
     /// Variance of the population is defined as:
@@ -278,15 +579,125 @@ where
     pub fn population_stddev(&self) -> Option<f64> {
         self.population_variance().map(|v| v.sqrt())
     }
+
+    /// Calculate skewness of the sample.
+    ///
+    /// Uses the adjusted Fisher–Pearson standardized moment coefficient:
+    /// $$
+    ///     G_1 = \\frac{\\sqrt{n (n - 1)}}{n - 2} \\cdot \\frac{m_3}{m_2^{3/2}}
+    /// $$
+    /// where $m_k = \\frac{1}{n} \\sum_{i=1}^{n} (x_i - \\overline{x})^k$
+    /// is the $k$-th central moment of the sample.
+    /// # Returns
+    /// * An [Option] containing the skewness if the sample has at least 3 points
+    ///   and they are not all equal.
+    /// # Panics
+    /// * If the data points cannot be converted to [f64].
+    pub fn skewness(&self) -> Option<f64> {
+        if self.len() < 3 {
+            return None;
+        }
+        let n = self.len() as f64;
+        let m2 = self.central_moment(2);
+        if m2 == 0.0 {
+            return None;
+        }
+        let m3 = self.central_moment(3);
+        Some((n * (n - 1.0)).sqrt() / (n - 2.0) * m3 / m2.powf(1.5))
+    }
+
+    /// Calculate excess kurtosis of the sample.
+    ///
+    /// Uses the adjusted estimator of excess kurtosis:
+    /// $$
+    ///     G_2 = \\frac{n - 1}{(n - 2)(n - 3)} \\left( (n + 1) \\left( \\frac{m_4}{m_2^2} - 3 \\right) + 6 \\right)
+    /// $$
+    /// where $m_k = \\frac{1}{n} \\sum_{i=1}^{n} (x_i - \\overline{x})^k$
+    /// is the $k$-th central moment of the sample.
+    /// Normal distribution has excess kurtosis of $0$.
+    /// # Returns
+    /// * An [Option] containing the excess kurtosis if the sample has at least 4 points
+    ///   and they are not all equal.
+    /// # Panics
+    /// * If the data points cannot be converted to [f64].
+    pub fn kurtosis(&self) -> Option<f64> {
+        if self.len() < 4 {
+            return None;
+        }
+        let n = self.len() as f64;
+        let m2 = self.central_moment(2);
+        if m2 == 0.0 {
+            return None;
+        }
+        let m4 = self.central_moment(4);
+        let g2 = m4 / (m2 * m2) - 3.0;
+        Some((n - 1.0) / ((n - 2.0) * (n - 3.0)) * ((n + 1.0) * g2 + 6.0))
+    }
+
+    /// Calculate the `k`-th central moment of a non-empty sample.
+    /// # Panics
+    /// * If the data points cannot be converted to [f64].
+    fn central_moment(&self, k: i32) -> f64 {
+        let mean = self.mean.unwrap();
+        self.data
+            .iter()
+            .map(|value| (value.to_f64().expect("Cannot convert to f64.") - mean).powi(k))
+            .sum::<f64>()
+            / self.len() as f64
+    }
+
+    /// Convert the data points to [f64] and sort them in ascending order.
+    /// # Panics
+    /// * If the data points cannot be converted to [f64].
+    /// * If the data points cannot be compared as [f64] values.
+    fn sorted_f64(&self) -> Vec<f64> {
+        let mut sorted = self
+            .data
+            .iter()
+            .map(|value| value.to_f64().expect("Cannot convert to f64."))
+            .collect::<Vec<_>>();
+        sorted.sort_unstable_by(|x, x1| x.partial_cmp(x1).expect("Cannot compare f64 values."));
+        sorted
+    }
 }
 impl<T> Default for Sample<T>
 where
-    T: Copy + FromPrimitive + ToPrimitive,
+    T: Copy + ToPrimitive,
 {
     fn default() -> Self {
         Self::new()
     }
 }
+impl<T> FromIterator<T> for Sample<T>
+where
+    T: Copy + ToPrimitive,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut sample = Self::new();
+        sample.extend(iter);
+        sample
+    }
+}
+impl<T> Extend<T> for Sample<T>
+where
+    T: Copy + ToPrimitive,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.data.reserve(iter.size_hint().0);
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+impl<'a, T> Extend<&'a T> for Sample<T>
+where
+    T: Copy + ToPrimitive,
+{
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
 impl<T> Deref for Sample<T> {
     type Target = Vec<T>;
 
@@ -294,3 +705,757 @@ impl<T> Deref for Sample<T> {
         &self.data
     }
 }
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Streaming statistics of a sequence of data points.
+///
+/// Unlike [Sample], this struct does not store the data points.
+/// It uses _Welford's online algorithm_ to keep track of the count,
+/// mean and variance in $O(1)$ memory, which makes it suitable
+/// for a very large (or unbounded) number of data points.
+/// # Example
+/// ```
+/// use pmath::statistics::RunningStats;
+///
+/// let mut stats = RunningStats::new();
+/// for x in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+///     stats.push(x);
+/// }
+///
+/// assert_eq!(stats.count(), 8);
+/// assert_eq!(stats.mean().unwrap(), 5.0);
+/// assert_eq!(stats.variance().unwrap(), 32.0 / 7.0);
+/// assert_eq!(stats.stddev().unwrap(), (32.0_f64 / 7.0).sqrt());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct RunningStats {
+    count: u64,
+    mean: f64,
+    m2: f64,
+}
+impl RunningStats {
+    /// Create a new [RunningStats] with no data points.
+    /// # Returns
+    /// * A new [RunningStats] instance.
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+        }
+    }
+
+    /// Add a data point.
+    /// # Arguments
+    /// * `x` - The data point to be added.
+    pub fn push(&mut self, x: f64) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    /// Get the number of data points.
+    /// # Returns
+    /// * The number of data points added so far.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Calculate arithmetic mean of the data points.
+    /// # Returns
+    /// * An [Option] containing the arithmetic mean if at least one data point was added.
+    pub fn mean(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.mean)
+        }
+    }
+
+    /// Calculate sample variance of the data points.
+    ///
+    /// See [Sample::sample_variance] for the definition.
+    /// # Returns
+    /// * An [Option] containing the sample variance if at least 2 data points were added.
+    pub fn variance(&self) -> Option<f64> {
+        if self.count < 2 {
+            None
+        } else {
+            Some(self.m2 / (self.count as f64 - 1.0))
+        }
+    }
+
+    /// Calculate sample standard deviation of the data points.
+    ///
+    /// See [Sample::sample_stddev] for the definition.
+    /// # Returns
+    /// * An [Option] containing the sample standard deviation if at least 2 data points were added.
+    pub fn stddev(&self) -> Option<f64> {
+        self.variance().map(|v| v.sqrt())
+    }
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Calculate weighted arithmetic mean.
+///
+/// Weighted arithmetic mean is defined as:
+/// $$
+///     \\overline{x}_w = \\frac{\\sum_{i=1}^{n} w_i x_i}{\\sum_{i=1}^{n} w_i}
+/// $$
+/// # Arguments
+/// * `values` - The data points.
+/// * `weights` - The weights of the data points.
+/// # Returns
+/// * An [Option] containing the weighted mean if `values` and `weights` have the same length
+///   and the total weight is not zero.
+/// # Example
+/// ```
+/// use pmath::statistics::weighted_mean;
+///
+/// assert_eq!(weighted_mean(&[1.0, 4.0], &[3.0, 1.0]), Some(1.75));
+/// ```
+pub fn weighted_mean(values: &[f64], weights: &[f64]) -> Option<f64> {
+    if values.len() != weights.len() {
+        return None;
+    }
+    let total_weight = weights.iter().sum::<f64>();
+    if total_weight == 0.0 {
+        return None;
+    }
+    let weighted_sum = values.iter().zip(weights).map(|(x, w)| w * x).sum::<f64>();
+    Some(weighted_sum / total_weight)
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Calculate covariance of two samples.
+///
+/// Sample covariance of paired data points is defined as:
+/// $$
+///     \\operatorname{cov}(x, y) = \\frac{\\sum_{i=1}^{n} (x_i - \\overline{x})(y_i - \\overline{y})}{n - 1}
+/// $$
+/// # Arguments
+/// * `a` - The first sample.
+/// * `b` - The second sample.
+/// # Returns
+/// * An [Option] containing the sample covariance if both samples have the same length
+///   of at least 2 points.
+/// # Example
+/// ```
+/// use pmath::statistics::{covariance, Sample};
+///
+/// let a = Sample::from_values([1.0, 2.0, 3.0, 4.0]);
+/// let b = Sample::from_values([2.0, 4.0, 6.0, 8.0]);
+/// assert_eq!(covariance(&a, &b).unwrap(), 10.0 / 3.0);
+/// ```
+pub fn covariance(a: &Sample<f64>, b: &Sample<f64>) -> Option<f64> {
+    if a.len() != b.len() || a.len() < 2 {
+        return None;
+    }
+    let (mean_a, mean_b) = (a.mean()?, b.mean()?);
+    let sum = a
+        .iter()
+        .zip(b.iter())
+        .map(|(x, y)| (x - mean_a) * (y - mean_b))
+        .sum::<f64>();
+    Some(sum / (a.len() as f64 - 1.0))
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Calculate Pearson correlation coefficient of two samples.
+///
+/// Pearson correlation coefficient is defined as:
+/// $$
+///     r = \\frac{\\operatorname{cov}(x, y)}{s_x s_y}
+/// $$
+/// where $s_x$ and $s_y$ are the sample standard deviations.
+/// It is always in the range $[-1, 1]$.
+/// # Arguments
+/// * `a` - The first sample.
+/// * `b` - The second sample.
+/// # Returns
+/// * An [Option] containing the correlation coefficient if both samples have the same length
+///   of at least 2 points and neither of them has all data points equal.
+/// # Example
+/// ```
+/// use pmath::statistics::{correlation, Sample};
+///
+/// let a = Sample::from_values([1.0, 2.0, 3.0, 4.0]);
+/// let b = Sample::from_values([8.0, 6.0, 4.0, 2.0]);
+/// assert!((correlation(&a, &b).unwrap() + 1.0).abs() < 1e-12);
+/// ```
+pub fn correlation(a: &Sample<f64>, b: &Sample<f64>) -> Option<f64> {
+    let cov = covariance(a, b)?;
+    let (stddev_a, stddev_b) = (a.sample_stddev()?, b.sample_stddev()?);
+    if stddev_a == 0.0 || stddev_b == 0.0 {
+        return None;
+    }
+    Some((cov / (stddev_a * stddev_b)).clamp(-1.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn geometric_harmonic_mean_invalid() {
+        //! Test that [Sample::geometric_mean] and [Sample::harmonic_mean] return [None]
+        //! for an empty sample or a sample with non-positive values
+
+        let sample = Sample::<f64>::new();
+        assert_eq!(sample.geometric_mean(), None);
+        assert_eq!(sample.harmonic_mean(), None);
+
+        let sample = Sample::from_values([1.0, 0.0, 2.0]);
+        assert_eq!(sample.geometric_mean(), None);
+        assert_eq!(sample.harmonic_mean(), None);
+
+        let sample = Sample::from_values([3, -1, 4]);
+        assert_eq!(sample.geometric_mean(), None);
+        assert_eq!(sample.harmonic_mean(), None);
+    }
+
+    #[test]
+    fn geometric_harmonic_mean_verify() {
+        //! Test [Sample::geometric_mean] and [Sample::harmonic_mean] against hand-computed means
+
+        // G = (1 * 2 * 4 * 8)^(1/4) = 64^(1/4) = 2 * sqrt(2)
+        // H = 4 / (1 + 1/2 + 1/4 + 1/8) = 32 / 15
+        let sample = Sample::from_values([1, 2, 4, 8]);
+        assert!((sample.geometric_mean().unwrap() - 2.0 * 2.0_f64.sqrt()).abs() < 1e-10);
+        assert!((sample.harmonic_mean().unwrap() - 32.0 / 15.0).abs() < 1e-10);
+
+        // G = (2 * 8)^(1/2) = 4, H = 2 / (1/2 + 1/8) = 3.2
+        let sample = Sample::from_values([2.0, 8.0]);
+        assert!((sample.geometric_mean().unwrap() - 4.0).abs() < 1e-10);
+        assert!((sample.harmonic_mean().unwrap() - 3.2).abs() < 1e-10);
+
+        // harmonic <= geometric <= arithmetic
+        let sample = Sample::from_values([3.0, 7.5, 1.25, 12.0, 4.0]);
+        assert!(sample.harmonic_mean().unwrap() <= sample.geometric_mean().unwrap());
+        assert!(sample.geometric_mean().unwrap() <= sample.mean().unwrap());
+    }
+
+    #[test]
+    fn min_max_empty() {
+        //! Test that [Sample::min] and [Sample::max] return [None] for an empty sample
+
+        let sample = Sample::<i32>::new();
+        assert_eq!(sample.min(), None);
+        assert_eq!(sample.max(), None);
+    }
+
+    #[test]
+    fn min_max_verify() {
+        //! Test [Sample::min] and [Sample::max] for a sample with negative values
+
+        let sample = Sample::from_values([3, -7, 12, 0, -2]);
+        assert_eq!(sample.min(), Some(-7.0));
+        assert_eq!(sample.max(), Some(12.0));
+
+        let sample = Sample::from_values([-1.5]);
+        assert_eq!(sample.min(), Some(-1.5));
+        assert_eq!(sample.max(), Some(-1.5));
+    }
+
+    #[test]
+    fn min_max_nan() {
+        //! Test that [Sample::min] and [Sample::max] ignore [f64::NAN] values
+
+        let sample = Sample::from_values([f64::NAN, 2.0, f64::NAN, -4.0]);
+        assert_eq!(sample.min(), Some(-4.0));
+        assert_eq!(sample.max(), Some(2.0));
+
+        let sample = Sample::from_values([f64::NAN]);
+        assert_eq!(sample.min(), None);
+        assert_eq!(sample.max(), None);
+    }
+
+    #[test]
+    fn median_empty() {
+        //! Test that [Sample::median] returns [None] for an empty sample
+
+        let sample = Sample::<f64>::new();
+        assert_eq!(sample.median(), None);
+    }
+
+    #[test]
+    fn median_single() {
+        //! Test [Sample::median] for a sample with a single data point
+
+        let sample = Sample::from_values([7]);
+        assert_eq!(sample.median(), Some(7.0));
+    }
+
+    #[test]
+    fn median_odd_length() {
+        //! Test [Sample::median] for a sample with an odd number of data points
+
+        let sample = Sample::from_values([5.0, 1.0, 9.0, 3.0, 7.0]);
+        assert_eq!(sample.median(), Some(5.0));
+    }
+
+    #[test]
+    fn median_even_length() {
+        //! Test [Sample::median] for a sample with an even number of data points
+
+        let sample = Sample::from_values([4, 1, 3, 2]);
+        assert_eq!(sample.median(), Some(2.5));
+    }
+
+    #[test]
+    fn quantile_empty() {
+        //! Test that [Sample::quantile] returns [None] for an empty sample
+
+        let sample = Sample::<f64>::new();
+        assert_eq!(sample.quantile(0.5), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn quantile_out_of_range() {
+        //! Test that [Sample::quantile] panics for `q` outside of `[0, 1]`
+
+        Sample::from_values([1.0, 2.0]).quantile(1.5);
+    }
+
+    #[test]
+    fn quantile_bounds() {
+        //! Test that [Sample::quantile] returns the minimum and maximum for `q = 0` and `q = 1`
+
+        let sample = Sample::from_values([7, -3, 12, 5, 0]);
+        assert_eq!(sample.quantile(0.0), Some(-3.0));
+        assert_eq!(sample.quantile(1.0), Some(12.0));
+    }
+
+    #[test]
+    fn quantile_matches_median() {
+        //! Test that [Sample::quantile] with `q = 0.5` matches [Sample::median]
+
+        let odd = Sample::from_values([9, 1, 8, 2, 7]);
+        assert_eq!(odd.quantile(0.5), odd.median());
+        let even = Sample::from_values([9, 1, 8, 2, 7, 3]);
+        assert_eq!(even.quantile(0.5), even.median());
+    }
+
+    #[test]
+    fn quantile_interpolated() {
+        //! Test [Sample::quantile] for quantiles that fall between two data points
+
+        // h = 9 * 0.95 = 8.55, so the result is 90 + 0.55 * (100 - 90)
+        let sample = Sample::from_values((1..=10).map(|x| x * 10));
+        assert!((sample.quantile(0.95).unwrap() - 95.5).abs() < 1e-10);
+        // h = 9 * 0.25 = 2.25, so the result is 30 + 0.25 * (40 - 30)
+        assert!((sample.quantile(0.25).unwrap() - 32.5).abs() < 1e-10);
+    }
+
+    #[test]
+    fn iqr_outliers_empty() {
+        //! Test [Sample::iqr] and [Sample::outliers] for an empty sample
+
+        let sample = Sample::<f64>::new();
+        assert_eq!(sample.iqr(), None);
+        assert!(sample.outliers(1.5).is_empty());
+    }
+
+    #[test]
+    fn iqr_verify() {
+        //! Test [Sample::iqr] against the quartiles
+
+        let sample = Sample::from_values([1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(sample.iqr(), Some(4.0));
+
+        let sample = Sample::from_values([7.0, 7.0, 7.0]);
+        assert_eq!(sample.iqr(), Some(0.0));
+    }
+
+    #[test]
+    fn outliers_verify() {
+        //! Test [Sample::outliers] on data with one obvious outlier
+
+        // Q1 = 3, Q3 = 5, IQR = 2, fences at 0 and 8
+        let sample = Sample::from_values([3, 5, 2, 4, 100, 3, 4, 2, 5]);
+        assert_eq!(sample.iqr(), Some(2.0));
+        assert_eq!(sample.outliers(1.5), vec![100.0]);
+        assert!(sample.outliers(100.0).is_empty());
+
+        // with k = 0, everything outside [Q1, Q3] is an outlier
+        assert_eq!(sample.outliers(0.0), vec![2.0, 100.0, 2.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn outliers_negative_k() {
+        //! Test that [Sample::outliers] panics for negative `k`
+
+        Sample::from_values([1, 2, 3]).outliers(-1.0);
+    }
+
+    #[test]
+    fn histogram_empty() {
+        //! Test that [Sample::histogram] returns no bins for an empty sample
+
+        let sample = Sample::<f64>::new();
+        assert!(sample.histogram(5).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn histogram_zero_bins() {
+        //! Test that [Sample::histogram] panics when asked for zero bins
+
+        Sample::from_values([1, 2, 3]).histogram(0);
+    }
+
+    #[test]
+    fn histogram_equal_values() {
+        //! Test that [Sample::histogram] returns a single degenerate bin when all values are equal
+
+        let sample = Sample::from_values([2.5, 2.5, 2.5]);
+        assert_eq!(sample.histogram(4), vec![(2.5, 2.5, 3)]);
+    }
+
+    #[test]
+    fn histogram_uniform() {
+        //! Test [Sample::histogram] on uniformly spread data
+
+        let sample = Sample::from_values(0..100);
+        let histogram = sample.histogram(4);
+        assert_eq!(histogram.len(), 4);
+        assert_eq!(histogram[0].0, 0.0);
+        assert_eq!(histogram[3].1, 99.0);
+        for window in histogram.windows(2) {
+            assert_eq!(window[0].1, window[1].0);
+        }
+        assert_eq!(
+            histogram
+                .iter()
+                .map(|&(_, _, count)| count)
+                .collect::<Vec<_>>(),
+            vec![25, 25, 25, 25]
+        );
+
+        // the maximum is included in the last bin
+        let sample = Sample::from_values([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        assert_eq!(
+            sample.histogram(5),
+            vec![
+                (0.0, 2.0, 2),
+                (2.0, 4.0, 2),
+                (4.0, 6.0, 2),
+                (6.0, 8.0, 2),
+                (8.0, 10.0, 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn mode_empty() {
+        //! Test that [Sample::mode] returns [None] for an empty sample
+
+        let sample = Sample::<u32>::new();
+        assert_eq!(sample.mode(), None);
+    }
+
+    #[test]
+    fn mode_verify() {
+        //! Test [Sample::mode] for a sample with a single most frequent value
+
+        let sample = Sample::from_values([4, 1, 4, 2, 4, 3, 1]);
+        assert_eq!(sample.mode(), Some(4.0));
+
+        let sample = Sample::from_values([0.5, -0.0, 1.5, 0.0, 0.5, 0.0]);
+        assert_eq!(sample.mode(), Some(0.0));
+    }
+
+    #[test]
+    fn mode_tie() {
+        //! Test that [Sample::mode] returns the smallest of the most frequent values on ties
+
+        let sample = Sample::from_values([5, 3, 9, 5, 3, 9, 1]);
+        assert_eq!(sample.mode(), Some(3.0));
+
+        let sample = Sample::from_values([2.5, -1.0, 7.0]);
+        assert_eq!(sample.mode(), Some(-1.0));
+    }
+
+    #[test]
+    fn variance_too_few_points() {
+        //! Test that the variance functions return [None] when there are too few data points
+
+        let mut sample = Sample::<f64>::new();
+        assert_eq!(sample.variance(), None);
+        assert_eq!(sample.sample_variance(), None);
+        assert_eq!(sample.sample_stddev(), None);
+        assert_eq!(sample.population_variance(), None);
+        assert_eq!(sample.population_stddev(), None);
+
+        sample.push(3.0);
+        assert_eq!(sample.variance(), None);
+        assert_eq!(sample.sample_variance(), None);
+        assert_eq!(sample.sample_stddev(), None);
+        assert_eq!(sample.population_variance(), Some(0.0));
+        assert_eq!(sample.population_stddev(), Some(0.0));
+    }
+
+    #[test]
+    fn variance_verify() {
+        //! Test the variance functions against hand-computed values
+
+        // mean = 5, sum of squared deviations = 9 + 1 + 1 + 1 + 0 + 0 + 4 + 16 = 32
+        let sample = Sample::from_values([2, 4, 4, 4, 5, 5, 7, 9]);
+        assert_eq!(sample.variance(), Some(32.0 / 7.0));
+        assert_eq!(sample.sample_variance(), Some(32.0 / 7.0));
+        assert_eq!(sample.sample_stddev(), Some((32.0_f64 / 7.0).sqrt()));
+        assert_eq!(sample.population_variance(), Some(4.0));
+        assert_eq!(sample.population_stddev(), Some(2.0));
+    }
+
+    #[test]
+    fn integer_matches_float() {
+        //! Test that [Sample] gives the same statistics for integer and floating point data
+
+        let data = [1500_u64, 1720, 1480, 2310, 1650, 1590, 1800, 1500];
+        let int_sample = Sample::<u64>::from_values(data);
+        let float_sample = data.iter().map(|&x| x as f64).collect::<Sample<f64>>();
+
+        assert_eq!(int_sample.mean(), float_sample.mean());
+        assert_eq!(int_sample.variance(), float_sample.variance());
+        assert_eq!(int_sample.stddev(), float_sample.stddev());
+        assert_eq!(int_sample.stddev(), int_sample.sample_stddev());
+        assert_eq!(int_sample.median(), float_sample.median());
+        assert_eq!(int_sample.mode(), float_sample.mode());
+        assert_eq!(int_sample.min(), float_sample.min());
+        assert_eq!(int_sample.max(), float_sample.max());
+        assert_eq!(int_sample.quantile(0.9), float_sample.quantile(0.9));
+
+        // u128 is what the benchmarks produce from nanosecond durations
+        let nanos_sample = data.iter().map(|&x| x as u128).collect::<Sample<u128>>();
+        assert_eq!(nanos_sample.mean(), float_sample.mean());
+        assert_eq!(nanos_sample.stddev(), float_sample.stddev());
+    }
+
+    #[test]
+    fn skewness_kurtosis_too_few_points() {
+        //! Test that [Sample::skewness] and [Sample::kurtosis] return [None] for too few
+        //! or constant data points
+
+        let sample = Sample::from_values([1.0, 2.0]);
+        assert_eq!(sample.skewness(), None);
+        let sample = Sample::from_values([1.0, 2.0, 3.0]);
+        assert!(sample.skewness().is_some());
+        assert_eq!(sample.kurtosis(), None);
+
+        let sample = Sample::from_values([4, 4, 4, 4, 4]);
+        assert_eq!(sample.skewness(), None);
+        assert_eq!(sample.kurtosis(), None);
+    }
+
+    #[test]
+    fn skewness_symmetric() {
+        //! Test that [Sample::skewness] is zero for symmetric data
+
+        let sample = Sample::from_values([1, 2, 3, 4, 5, 6, 7]);
+        assert!(sample.skewness().unwrap().abs() < 1e-12);
+        let sample = Sample::from_values([-10.0, -1.0, 0.0, 1.0, 10.0]);
+        assert!(sample.skewness().unwrap().abs() < 1e-12);
+    }
+
+    #[test]
+    fn skewness_kurtosis_verify() {
+        //! Test [Sample::skewness] and [Sample::kurtosis] against hand-computed values
+
+        // mean = 3, deviations from the mean are -2, -2, -2, -1 and 7,
+        // so m2 = 62 / 5, m3 = 318 / 5 and m4 = 2450 / 5
+        let sample = Sample::from_values([1, 1, 1, 2, 10]);
+        let skewness = 2.171292493874225;
+        let kurtosis = 4.747138397502599;
+
+        // the long right tail gives positive skewness and kurtosis
+        assert!(sample.skewness().unwrap() > 0.0);
+        assert!(sample.kurtosis().unwrap() > 0.0);
+        assert!((sample.skewness().unwrap() - skewness).abs() < 1e-10);
+        assert!((sample.kurtosis().unwrap() - kurtosis).abs() < 1e-10);
+
+        // mirrored data has opposite skewness and the same kurtosis
+        let mirrored = Sample::from_values([-1, -1, -1, -2, -10]);
+        assert!((mirrored.skewness().unwrap() + skewness).abs() < 1e-10);
+        assert!((mirrored.kurtosis().unwrap() - kurtosis).abs() < 1e-10);
+    }
+
+    #[test]
+    fn from_iterator() {
+        //! Test collecting an iterator into a [Sample]
+
+        let data = [1.0, 2.0, 3.0, 4.0];
+        let sample: Sample<f64> = data.iter().copied().collect();
+        assert_eq!(sample.as_slice(), data.as_slice());
+        assert_eq!(sample.mean(), Some(2.5));
+        assert!(sample == Sample::from_values(data));
+
+        let sample = (1..=5u64).collect::<Sample<_>>();
+        assert_eq!(sample.len(), 5);
+        assert_eq!(sample.mean(), Some(3.0));
+    }
+
+    #[test]
+    fn extend() {
+        //! Test extending a [Sample] by values and by references
+
+        let mut sample = Sample::from_values([1, 2]);
+        sample.extend([3, 4]);
+        sample.extend(&[5, 6]);
+        assert_eq!(sample.as_slice(), &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(sample.mean(), Some(3.5));
+        assert_eq!(sample.variance(), Some(3.5));
+    }
+
+    #[test]
+    fn merge_verify() {
+        //! Test that merging two samples is the same as pushing all data points into one sample
+
+        let a = [3.5, -1.0, 8.25, 4.0, 0.5];
+        let b = [10.0, 2.0, -6.5];
+        let combined = Sample::<f64>::from_values(a.iter().chain(b.iter()));
+
+        let mut merged = Sample::from_values(a);
+        merged.merge(&Sample::from_values(b));
+        assert_eq!(merged.as_slice(), combined.as_slice());
+        assert!((merged.mean().unwrap() - combined.mean().unwrap()).abs() < 1e-10);
+        assert!((merged.variance().unwrap() - combined.variance().unwrap()).abs() < 1e-10);
+
+        let merged = Sample::from_values(a).merged(Sample::from_values(b));
+        assert_eq!(merged.as_slice(), combined.as_slice());
+        assert!((merged.mean().unwrap() - combined.mean().unwrap()).abs() < 1e-10);
+        assert!((merged.variance().unwrap() - combined.variance().unwrap()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn merge_empty() {
+        //! Test merging with empty samples
+
+        let sample = Sample::from_values([1, 2, 3]);
+
+        let merged = Sample::new().merged(sample.clone());
+        assert_eq!(merged.as_slice(), sample.as_slice());
+        assert_eq!(merged.mean(), sample.mean());
+        assert_eq!(merged.variance(), sample.variance());
+
+        let merged = sample.clone().merged(Sample::new());
+        assert_eq!(merged.as_slice(), sample.as_slice());
+        assert_eq!(merged.mean(), sample.mean());
+        assert_eq!(merged.variance(), sample.variance());
+
+        let merged = Sample::<i32>::new().merged(Sample::new());
+        assert!(merged.is_empty());
+        assert_eq!(merged.mean(), None);
+    }
+
+    #[test]
+    fn running_stats_empty() {
+        //! Test [RunningStats] with too few data points
+
+        let mut stats = RunningStats::new();
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.variance(), None);
+        assert_eq!(stats.stddev(), None);
+
+        stats.push(-2.5);
+        assert_eq!(stats.count(), 1);
+        assert_eq!(stats.mean(), Some(-2.5));
+        assert_eq!(stats.variance(), None);
+        assert_eq!(stats.stddev(), None);
+    }
+
+    #[test]
+    fn running_stats_matches_sample() {
+        //! Test that [RunningStats] gives the same results as [Sample] on the same data
+
+        let data = (1..=1000)
+            .map(|i| ((i * 7919) % 1009) as f64 / 3.0 - 50.0)
+            .collect::<Vec<_>>();
+
+        let mut stats = RunningStats::new();
+        for &x in &data {
+            stats.push(x);
+        }
+        let sample = Sample::<f64>::from_values(&data);
+
+        assert_eq!(stats.count(), sample.len() as u64);
+        assert!((stats.mean().unwrap() - sample.mean().unwrap()).abs() < 1e-9);
+        assert!((stats.variance().unwrap() - sample.variance().unwrap()).abs() < 1e-9);
+        assert!((stats.stddev().unwrap() - sample.sample_stddev().unwrap()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn weighted_mean_invalid() {
+        //! Test that [weighted_mean] returns [None] for mismatched lengths or zero total weight
+
+        assert_eq!(weighted_mean(&[1.0, 2.0], &[1.0]), None);
+        assert_eq!(weighted_mean(&[], &[]), None);
+        assert_eq!(weighted_mean(&[1.0, 2.0], &[0.0, 0.0]), None);
+        assert_eq!(weighted_mean(&[1.0, 2.0], &[1.0, -1.0]), None);
+    }
+
+    #[test]
+    fn weighted_mean_verify() {
+        //! Test [weighted_mean] against hand-computed weighted averages
+
+        // (2 * 10 + 3 * 20 + 5 * 30) / 10 = 23
+        assert_eq!(
+            weighted_mean(&[10.0, 20.0, 30.0], &[2.0, 3.0, 5.0]),
+            Some(23.0)
+        );
+        // (100 * 0.5 + 300 * 1.5) / 2 = 250
+        assert_eq!(weighted_mean(&[100.0, 300.0], &[0.5, 1.5]), Some(250.0));
+
+        // equal weights give the arithmetic mean
+        let values = [3.0, 7.5, 1.25, 12.0];
+        let sample = Sample::from_values(values);
+        assert!(
+            (weighted_mean(&values, &[4.0; 4]).unwrap() - sample.mean().unwrap()).abs() < 1e-10
+        );
+    }
+
+    #[test]
+    fn covariance_correlation_invalid() {
+        //! Test that [covariance] and [correlation] return [None] for mismatched or too short samples
+
+        let a = Sample::from_values([1.0, 2.0, 3.0]);
+        let b = Sample::from_values([1.0, 2.0]);
+        assert_eq!(covariance(&a, &b), None);
+        assert_eq!(correlation(&a, &b), None);
+
+        let a = Sample::from_values([1.0]);
+        let b = Sample::from_values([2.0]);
+        assert_eq!(covariance(&a, &b), None);
+        assert_eq!(correlation(&a, &b), None);
+
+        let a = Sample::<f64>::new();
+        let b = Sample::<f64>::new();
+        assert_eq!(covariance(&a, &b), None);
+        assert_eq!(correlation(&a, &b), None);
+
+        // zero variance
+        let a = Sample::from_values([1.0, 2.0, 3.0]);
+        let b = Sample::from_values([5.0, 5.0, 5.0]);
+        assert_eq!(covariance(&a, &b), Some(0.0));
+        assert_eq!(correlation(&a, &b), None);
+    }
+
+    #[test]
+    fn covariance_correlation_verify() {
+        //! Test [covariance] and [correlation] on perfectly correlated and anticorrelated data
+
+        let a = Sample::from_values([1.0, 2.0, 3.0, 4.0, 5.0]);
+        let b = Sample::from_values([3.0, 5.0, 7.0, 9.0, 11.0]);
+        assert!((covariance(&a, &b).unwrap() - 5.0).abs() < 1e-10);
+        assert!((covariance(&a, &a).unwrap() - a.variance().unwrap()).abs() < 1e-10);
+        assert!((correlation(&a, &b).unwrap() - 1.0).abs() < 1e-10);
+
+        let b = Sample::from_values([10.0, 7.5, 5.0, 2.5, 0.0]);
+        assert!((covariance(&a, &b).unwrap() + 6.25).abs() < 1e-10);
+        assert!((correlation(&a, &b).unwrap() + 1.0).abs() < 1e-10);
+    }
+}
@@ -1,11 +1,16 @@
 use js_sys::{Array, Number, Object, Reflect};
-use peuler::{PEuler as libPEuler, ProjectEuler};
+use malachite::Natural;
+use malachite::base::num::arithmetic::traits::Factorial;
+use peuler::{BenchmarkResult, PEuler as libPEuler, Problem, ProjectEuler, Solution};
+use pmath::SimpleContinuedFraction;
 use pmath::statistics::Sample as libSample;
+use std::sync::atomic::{AtomicBool, Ordering};
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
 pub struct PEuler {
     inner: libPEuler,
+    cancelled: AtomicBool,
 }
 #[wasm_bindgen]
 impl PEuler {
@@ -13,6 +18,7 @@ impl PEuler {
     pub fn new() -> Self {
         Self {
             inner: libPEuler::new(),
+            cancelled: AtomicBool::new(false),
         }
     }
 
@@ -20,12 +26,9 @@ impl PEuler {
         let arr = Array::new();
         for p in self.inner.problems() {
             let obj = Object::new();
-            Reflect::set(&obj, &JsValue::from_str("id"), &Number::from(p.id() as u32))?;
-            Reflect::set(
-                &obj,
-                &JsValue::from_str("title"),
-                &JsValue::from_str(p.title()),
-            )?;
+            for (key, value) in problem_metadata(p) {
+                Reflect::set(&obj, &JsValue::from_str(key), &value.into())?;
+            }
             arr.push(&obj);
         }
         Ok(arr)
@@ -33,10 +36,30 @@ impl PEuler {
 
     pub fn solve(&self, id: usize) -> Result<String, JsValue> {
         self.inner
-            .solve(id)
+            .solve_cached(id)
             .map_err(|e| JsValue::from_str(&e.to_string()))
     }
 
+    /// Solve the problem, unless [PEuler::cancel] was called since the last cancelled solve.
+    ///
+    /// The solve runs synchronously, so it cannot be aborted while it is running,
+    /// see [PEuler::cancel].
+    pub fn solve_cancellable(&self, id: usize) -> Result<String, JsValue> {
+        self.try_solve_cancellable(id)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// Request the cancellation of the next cancellable solve.
+    ///
+    /// This does not abort a solve that is already running.
+    /// The module is built without shared memory, so the flag is only visible to
+    /// the thread that owns this instance, and that thread is busy until the solve returns.
+    /// A pending cancellation makes the next [PEuler::solve_cancellable] fail without solving.
+    /// To abort a running solve, terminate the Web Worker that runs it, as the website does.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
     pub fn benchmark(&self, id: usize) -> Result<Object, JsValue> {
         let (res, dur) = self
             .inner
@@ -53,6 +76,52 @@ impl PEuler {
 
         Ok(obj)
     }
+
+    pub fn benchmark_n(&self, id: usize, iterations: usize) -> Result<Object, JsValue> {
+        if iterations == 0 {
+            return Err(JsValue::from_str("Iterations must be positive."));
+        }
+        let stats = self
+            .inner
+            .problem(id)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?
+            .benchmark_stats(iterations);
+
+        benchmark_object(&stats)
+    }
+
+    pub fn benchmark_with_progress(
+        &self,
+        id: usize,
+        iterations: usize,
+        callback: Option<js_sys::Function>,
+    ) -> Result<Object, JsValue> {
+        if iterations == 0 {
+            return Err(JsValue::from_str("Iterations must be positive."));
+        }
+        let problem = self
+            .inner
+            .problem(id)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        let stats = benchmark_progress(problem, iterations, |completed| match &callback {
+            Some(callback) => callback
+                .call1(&JsValue::NULL, &Number::from(completed as f64))
+                .map(drop),
+            None => Ok(()),
+        })?;
+
+        benchmark_object(&stats)
+    }
+}
+impl PEuler {
+    fn try_solve_cancellable(&self, id: usize) -> Result<String, peuler::Error> {
+        let result = self.inner.solve_cancellable(id, &self.cancelled);
+        if result == Err(peuler::Error::Cancelled) {
+            // the cancellation is consumed, so that the next solve can run
+            self.cancelled.store(false, Ordering::Relaxed);
+        }
+        result
+    }
 }
 impl Default for PEuler {
     fn default() -> Self {
@@ -60,6 +129,91 @@ impl Default for PEuler {
     }
 }
 
+/// A value describing a problem, converted into the matching JS type.
+#[derive(Debug, PartialEq)]
+enum Metadata {
+    Number(Option<u32>),
+    Text(String),
+    List(&'static [&'static str]),
+}
+impl From<Metadata> for JsValue {
+    fn from(metadata: Metadata) -> Self {
+        match metadata {
+            Metadata::Number(number) => number.map_or(JsValue::NULL, |n| Number::from(n).into()),
+            Metadata::Text(text) => JsValue::from_str(&text),
+            Metadata::List(list) => list
+                .iter()
+                .map(|item| JsValue::from_str(item))
+                .collect::<Array>()
+                .into(),
+        }
+    }
+}
+
+/// The metadata of a problem, keyed by their names in JS.
+///
+/// A missing difficulty is represented as `null`.
+fn problem_metadata(problem: &dyn Problem) -> [(&'static str, Metadata); 5] {
+    [
+        ("id", Metadata::Number(Some(problem.id() as u32))),
+        ("title", Metadata::Text(problem.title().to_string())),
+        ("url", Metadata::Text(problem.url())),
+        (
+            "difficulty",
+            Metadata::Number(problem.difficulty().map(u32::from)),
+        ),
+        ("tags", Metadata::List(problem.tags())),
+    ]
+}
+
+/// Benchmark the problem, reporting the number of completed iterations along the way.
+///
+/// The progress is reported about a hundred times, and always after the last iteration.
+/// The benchmark stops at the first error returned by `progress`.
+fn benchmark_progress<E>(
+    problem: &dyn Problem,
+    iterations: usize,
+    mut progress: impl FnMut(usize) -> Result<(), E>,
+) -> Result<BenchmarkResult, E> {
+    let step = (iterations / 100).max(1);
+    let mut answer = String::new();
+    let mut sample = libSample::new();
+    for completed in 1..=iterations {
+        let (solution, elapsed) = problem.benchmark();
+        answer = solution;
+        sample.push(u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX));
+        if completed.is_multiple_of(step) || completed == iterations {
+            progress(completed)?;
+        }
+    }
+    Ok(BenchmarkResult::new(answer, &sample))
+}
+
+/// The JS object with the answer and the timing statistics of a benchmark.
+fn benchmark_object(stats: &BenchmarkResult) -> Result<Object, JsValue> {
+    let obj = Object::new();
+    Reflect::set(
+        &obj,
+        &JsValue::from_str("result"),
+        &JsValue::from_str(&stats.answer),
+    )?;
+    for (key, value) in benchmark_statistics(stats) {
+        Reflect::set(&obj, &JsValue::from_str(key), &Number::from(value))?;
+    }
+    Ok(obj)
+}
+
+/// The timing statistics of a benchmark, in nanoseconds, keyed by their names in JS.
+fn benchmark_statistics(stats: &BenchmarkResult) -> [(&'static str, f64); 5] {
+    [
+        ("mean", stats.mean_ns),
+        ("stddev", stats.stddev_ns),
+        ("min", stats.min_ns as f64),
+        ("max", stats.max_ns as f64),
+        ("median", stats.median_ns),
+    ]
+}
+
 #[wasm_bindgen]
 pub struct Sample {
     inner: libSample<f64>,
@@ -89,12 +243,40 @@ impl Sample {
         self.inner.push(value);
     }
 
+    pub fn push_all(&mut self, values: &[f64]) {
+        self.inner.extend(values);
+    }
+
     pub fn mean(&self) -> Option<f64> {
         self.inner.mean()
     }
 
     pub fn stddev(&self) -> Option<f64> {
-        self.inner.sample_stddev()
+        self.inner.stddev()
+    }
+
+    pub fn min(&self) -> Option<f64> {
+        self.inner.min()
+    }
+
+    pub fn max(&self) -> Option<f64> {
+        self.inner.max()
+    }
+
+    pub fn variance(&self) -> Option<f64> {
+        self.inner.variance()
+    }
+
+    pub fn median(&self) -> Option<f64> {
+        self.inner.median()
+    }
+
+    pub fn quantile(&self, q: f64) -> Result<Option<f64>, JsValue> {
+        // the library panics on an invalid quantile, which would abort the wasm module
+        if !(0.0..=1.0).contains(&q) {
+            return Err(JsValue::from_str("Quantile must be in the range [0, 1]."));
+        }
+        Ok(self.inner.quantile(q))
     }
 }
 impl Default for Sample {
@@ -102,3 +284,314 @@ impl Default for Sample {
         Self::new()
     }
 }
+
+#[wasm_bindgen]
+pub struct ContinuedFraction {
+    inner: SimpleContinuedFraction<u32>,
+}
+#[wasm_bindgen]
+impl ContinuedFraction {
+    pub fn from_sqrt(n: u32) -> Self {
+        Self {
+            inner: SimpleContinuedFraction::from_sqrt(n),
+        }
+    }
+
+    pub fn non_periodic(&self) -> Vec<u32> {
+        self.inner.non_periodic().to_vec()
+    }
+
+    pub fn periodic(&self) -> Option<Vec<u32>> {
+        self.inner.periodic().map(<[u32]>::to_vec)
+    }
+
+    pub fn convergent(&self, n: usize) -> Option<Array> {
+        self.convergent_parts(n).map(|(numerator, denominator)| {
+            Array::of2(
+                &JsValue::from_str(&numerator),
+                &JsValue::from_str(&denominator),
+            )
+        })
+    }
+}
+impl ContinuedFraction {
+    /// The numerator and the denominator of the `n`-th convergent, starting from `0`.
+    fn convergent_parts(&self, n: usize) -> Option<(String, String)> {
+        self.inner.convergents().nth(n).map(|convergent| {
+            (
+                convergent.numerator_ref().to_string(),
+                convergent.denominator_ref().to_string(),
+            )
+        })
+    }
+}
+
+#[wasm_bindgen]
+pub fn gcd(a: u64, b: u64) -> u64 {
+    pmath::gcd(a, b)
+}
+
+// the least common multiple of two u64 values always fits in u128,
+// which is passed to JS as a string since it may exceed u64
+#[wasm_bindgen]
+pub fn lcm(a: u64, b: u64) -> String {
+    pmath::lcm(u128::from(a), u128::from(b)).to_string()
+}
+
+#[wasm_bindgen]
+pub fn isqrt(n: u64) -> u64 {
+    pmath::isqrt(n)
+}
+
+#[wasm_bindgen]
+pub fn factorial(n: u64) -> String {
+    Natural::factorial(n).to_string()
+}
+
+/// Check whether `n` is prime.
+///
+/// The input is capped at [u64::MAX] (a `BigInt` in JS).
+/// The deterministic Miller-Rabin test is used, so large values are checked quickly.
+#[wasm_bindgen]
+pub fn is_prime(n: u64) -> bool {
+    pmath::primes::is_prime_mr(n)
+}
+
+/// Factorize `n` into `[prime, exponent]` pairs in ascending order of the primes.
+///
+/// The input is capped at [u64::MAX] (a `BigInt` in JS), so the primes are returned as `BigInt`s.
+/// `0` and `1` have no prime factors and yield an empty array.
+/// Pollard's rho is used, so products of large primes are factorized quickly.
+#[wasm_bindgen]
+pub fn prime_factors(n: u64) -> Array {
+    pmath::factors::factorize(n)
+        .into_iter()
+        .map(|(prime, exponent)| Array::of2(&JsValue::from(prime), &Number::from(exponent)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn benchmark_statistics_keys() {
+        //! Test the keys of the statistics returned by [PEuler::benchmark_n].
+
+        let stats = libPEuler::new().problem(1).unwrap().benchmark_stats(3);
+        let statistics = benchmark_statistics(&stats);
+        assert_eq!(
+            statistics.map(|(key, _)| key),
+            ["mean", "stddev", "min", "max", "median"]
+        );
+
+        let value = |name| statistics.iter().find(|(key, _)| *key == name).unwrap().1;
+        assert!(value("min") <= value("median"));
+        assert!(value("median") <= value("max"));
+        assert!(value("min") <= value("mean"));
+        assert!(value("mean") <= value("max"));
+    }
+
+    #[test]
+    fn problem_metadata_keys() {
+        //! Test the keys and values of the metadata returned by [PEuler::problems].
+
+        let project_euler = libPEuler::new();
+        let metadata = problem_metadata(project_euler.problem(3).unwrap());
+        assert_eq!(
+            metadata.each_ref().map(|(key, _)| *key),
+            ["id", "title", "url", "difficulty", "tags"]
+        );
+        assert_eq!(
+            metadata.map(|(_, value)| value),
+            [
+                Metadata::Number(Some(3)),
+                Metadata::Text(String::from("Largest Prime Factor")),
+                Metadata::Text(String::from("https://projecteuler.net/problem=3")),
+                Metadata::Number(Some(5)),
+                Metadata::List(&["primes"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn benchmark_progress_calls() {
+        //! Test how many times [benchmark_progress] reports the progress.
+
+        let project_euler = libPEuler::new();
+        let problem = project_euler.problem(1).unwrap();
+
+        for (iterations, expected) in [(1, vec![1]), (5, vec![1, 2, 3, 4, 5])] {
+            let mut reported = Vec::new();
+            let stats = benchmark_progress::<()>(problem, iterations, |completed| {
+                reported.push(completed);
+                Ok(())
+            })
+            .unwrap();
+            assert_eq!(reported, expected);
+            assert_eq!(stats.iterations, iterations);
+            assert_eq!(stats.answer, "233168");
+        }
+
+        let mut calls = 0;
+        benchmark_progress::<()>(problem, 250, |completed| {
+            calls += 1;
+            assert!(completed.is_multiple_of(2));
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(calls, 125);
+
+        // the benchmark stops at the first error
+        let mut calls = 0;
+        let result = benchmark_progress(problem, 10, |completed| {
+            calls += 1;
+            if completed == 3 {
+                Err(completed)
+            } else {
+                Ok(())
+            }
+        });
+        assert_eq!(result.map(|stats| stats.iterations), Err(3));
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn solve_cancellable() {
+        //! Test that a pending [PEuler::cancel] rejects only the next cancellable solve.
+
+        let project_euler = PEuler::new();
+        project_euler.cancel();
+        assert_eq!(
+            project_euler.try_solve_cancellable(14),
+            Err(peuler::Error::Cancelled)
+        );
+        assert_eq!(
+            project_euler.try_solve_cancellable(14),
+            Ok(String::from("837799"))
+        );
+    }
+
+    #[test]
+    fn continued_fraction_sqrt() {
+        //! Test [ContinuedFraction] of the square roots of 2 and 16.
+
+        let cf = ContinuedFraction::from_sqrt(2);
+        assert_eq!(cf.non_periodic(), vec![1]);
+        assert_eq!(cf.periodic(), Some(vec![2]));
+        assert_eq!(
+            (0..4)
+                .map(|n| cf.convergent_parts(n).unwrap())
+                .collect::<Vec<_>>(),
+            [("1", "1"), ("3", "2"), ("7", "5"), ("17", "12")]
+                .map(|(numerator, denominator)| (numerator.to_string(), denominator.to_string()))
+        );
+
+        let cf = ContinuedFraction::from_sqrt(16);
+        assert_eq!(cf.non_periodic(), vec![4]);
+        assert_eq!(cf.periodic(), None);
+        assert_eq!(
+            cf.convergent_parts(0),
+            Some((String::from("4"), String::from("1")))
+        );
+        assert_eq!(cf.convergent_parts(1), None);
+    }
+
+    #[test]
+    fn math_helpers() {
+        //! Test the math helpers exposed to JS.
+
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(lcm(12, 18), "36");
+        assert_eq!(
+            lcm(u64::MAX, u64::MAX - 1),
+            (u128::from(u64::MAX) * u128::from(u64::MAX - 1)).to_string()
+        );
+        assert_eq!(isqrt(17), 4);
+        assert_eq!(isqrt(u64::MAX), u64::from(u32::MAX));
+        assert_eq!(factorial(0), "1");
+        assert_eq!(factorial(25), "15511210043330985984000000");
+    }
+
+    #[test]
+    fn number_theory_helpers() {
+        //! Test the primality and factorization helpers exposed to JS.
+
+        use pmath::factors::factorize;
+
+        assert!(!is_prime(0));
+        assert!(!is_prime(1));
+        assert!(is_prime(2));
+        assert!(is_prime(97));
+        assert!(!is_prime(360));
+        assert!(is_prime(1_000_000_007));
+        assert!(is_prime(18_446_744_073_709_551_557));
+        assert!(!is_prime(3_215_031_751));
+
+        assert_eq!(factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(factorize(97), vec![(97, 1)]);
+        assert_eq!(
+            factorize(2 * 1_000_000_007),
+            vec![(2, 1), (1_000_000_007, 1)]
+        );
+        assert_eq!(
+            factorize(18_446_743_979_220_271_189),
+            vec![(4_294_967_279, 1), (4_294_967_291, 1)]
+        );
+        assert_eq!(factorize(1), vec![]);
+        assert_eq!(factorize(0), vec![]);
+    }
+
+    #[test]
+    fn sample_statistics() {
+        //! Test the statistics of [Sample] on a small dataset.
+
+        let mut sample = Sample::new();
+        assert_eq!(sample.median(), None);
+        assert_eq!(sample.variance(), None);
+        assert_eq!(sample.quantile(0.5), Ok(None));
+
+        for value in [4.0, 1.0, 3.0, 2.0] {
+            sample.push(value);
+        }
+        assert_eq!(sample.median(), Some(2.5));
+        assert!((sample.variance().unwrap() - 5.0 / 3.0).abs() < 1e-12);
+        assert_eq!(sample.quantile(0.0), Ok(Some(1.0)));
+        assert_eq!(sample.quantile(0.75), Ok(Some(3.25)));
+        assert_eq!(sample.quantile(1.0), Ok(Some(4.0)));
+    }
+
+    #[test]
+    fn sample_push_all() {
+        //! Test [Sample::push_all] on a small dataset.
+
+        let mut sample = Sample::new();
+        sample.push_all(&[]);
+        assert!(sample.is_empty());
+
+        sample.push_all(&[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(sample.len(), 4);
+        assert_eq!(sample.mean(), Some(2.5));
+
+        sample.push(5.0);
+        sample.push_all(&[6.0]);
+        assert_eq!(sample.len(), 6);
+        assert_eq!(sample.mean(), Some(3.5));
+    }
+
+    #[test]
+    fn sample_min_max() {
+        //! Test [Sample::min] and [Sample::max] on a small dataset.
+
+        let mut sample = Sample::new();
+        assert_eq!(sample.min(), None);
+        assert_eq!(sample.max(), None);
+
+        for value in [3.5, -1.0, 7.25, 0.0] {
+            sample.push(value);
+        }
+        assert_eq!(sample.min(), Some(-1.0));
+        assert_eq!(sample.max(), Some(7.25));
+    }
+}